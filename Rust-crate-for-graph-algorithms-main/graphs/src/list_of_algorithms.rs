@@ -2,4 +2,29 @@ pub mod dijkstras;
 pub mod bellmanford;
 pub mod dfs;
 pub mod kosaraju;
-pub mod bfs;
\ No newline at end of file
+pub mod bfs;
+pub mod biconnected;
+pub mod connected_components;
+pub mod mst;
+pub mod flow;
+pub mod min_cost_flow;
+pub mod bipartite_matching;
+pub mod hungarian;
+pub mod blossom;
+pub mod bipartite;
+pub mod cycles;
+pub mod euler;
+pub mod simple_paths;
+pub mod k_shortest_paths;
+pub mod distance_metrics;
+pub(crate) mod random;
+pub mod centrality;
+pub mod link_analysis;
+pub mod community;
+pub mod clustering;
+pub mod cliques;
+pub mod coloring;
+pub mod isomorphism;
+pub mod dominators;
+pub mod closure;
+pub mod min_cut;
\ No newline at end of file
@@ -2,7 +2,7 @@ pub mod bfs {
 
     use std::usize;
     use std::{
-        collections::{HashSet, VecDeque},
+        collections::VecDeque,
         io::{stdin, stdout, Write},
     };
 
@@ -83,19 +83,60 @@ pub mod bfs {
         ///
 
         pub fn b_fs(&self, start: usize) -> Vec<usize> {
-            let mut visited = HashSet::new();
+            let mut parent = vec![usize::MAX; self.edges.len()];
+            let mut depth = vec![usize::MAX; self.edges.len()];
+            let visited_vec = self.bfs_tree(start, &mut parent, &mut depth);
+            for u in &visited_vec {
+                println!("Visited node: {}", u);
+            }
+            visited_vec
+        }
+
+        /// Performs a Breadth-First Search from `start` that records the BFS tree instead of printing the visits.
+        /// Vertices that already have a depth are treated as visited, so the same buffers can be reused to cover
+        /// every component of the graph one start vertex at a time.
+        ///
+        /// # Arguments
+        ///
+        /// * `start` - The index of the vertex to start the Breadth-First Search from.
+        /// * `parent` - Receives the parent of every reached vertex in the BFS tree. The start vertex keeps `usize::MAX`.
+        /// * `depth` - Receives the number of edges from `start` to every reached vertex. `usize::MAX` marks an unvisited vertex.
+        ///
+        /// # Returns
+        ///
+        /// * visited - The newly reached vertices in the order they were visited.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// let mut g = Graph::new(4);
+        /// g.add_edge(0, 1);
+        /// g.add_edge(1, 2);
+        ///
+        /// let mut parent = vec![usize::MAX; 4];
+        /// let mut depth = vec![usize::MAX; 4];
+        /// assert_eq!(g.bfs_tree(0, &mut parent, &mut depth), vec![0, 1, 2]);
+        /// assert_eq!(parent[2], 1);
+        /// assert_eq!(depth, vec![0, 1, 2, usize::MAX]);
+        /// ```
+        pub fn bfs_tree(
+            &self,
+            start: usize,
+            parent: &mut [usize],
+            depth: &mut [usize],
+        ) -> Vec<usize> {
             let mut queue = VecDeque::new();
             let mut visited_vec = Vec::new();
 
-            visited.insert(start);
+            depth[start] = 0;
             queue.push_back(start);
 
             while let Some(u) = queue.pop_front() {
-                println!("Visited node: {}", u);
                 visited_vec.push(u);
                 for &v in &self.edges[u] {
-                    if !visited.contains(&v) {
-                        visited.insert(v);
+                    if depth[v] == usize::MAX {
+                        parent[v] = u;
+                        depth[v] = depth[u] + 1;
                         queue.push_back(v);
                     }
                 }
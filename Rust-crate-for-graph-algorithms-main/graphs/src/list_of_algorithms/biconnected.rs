@@ -0,0 +1,355 @@
+pub mod biconnected {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::dfs::dfs::{add_edges, Graph};
+    use std::io::{stdin, stdout, Write};
+
+    /// Result of the biconnected-component decomposition of an undirected graph.
+    pub struct Biconnected {
+        /// The blocks (biconnected components), each given as its list of edges `(u, v)` with `u <= v`.
+        pub blocks: Vec<Vec<(usize, usize)>>,
+        /// The cut vertices (articulation points) of the graph in sorted order.
+        pub articulation_points: Vec<usize>,
+        /// The bridges of the graph, each given as `(u, v)` with `u < v`.
+        pub bridges: Vec<(usize, usize)>,
+    }
+
+    /// The block-cut tree of an undirected graph.
+    /// Tree nodes are either blocks or cut vertices, and every tree edge joins a block to a cut vertex it contains.
+    pub struct BlockCutTree {
+        /// The vertices of each block in sorted order, indexed like `Biconnected::blocks`.
+        pub blocks: Vec<Vec<usize>>,
+        /// The cut vertices of the graph in sorted order.
+        pub cut_vertices: Vec<usize>,
+        /// The tree edges as `(block index, cut vertex)` pairs.
+        pub edges: Vec<(usize, usize)>,
+    }
+
+    //State shared by the recursive DFS below
+    struct Search {
+        disc: Vec<usize>,              //discovery time of each vertex, usize::MAX when unvisited
+        low: Vec<usize>,               //lowest discovery time reachable through the DFS subtree
+        time: usize,                   //next discovery time
+        stack: Vec<(usize, usize)>,    //edges of the blocks that are still open
+        blocks: Vec<Vec<(usize, usize)>>,
+        is_cut: Vec<bool>,
+        bridges: Vec<(usize, usize)>,
+    }
+
+    /// Decomposes an undirected graph into its biconnected components using the Hopcroft-Tarjan algorithm.
+    /// The graph must store every undirected edge in both directions, as `dfs::add_edges` does.
+    /// Self-loops are ignored and parallel edges are kept inside the same block.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph represented as a symmetric adjacency list.
+    ///
+    /// # Returns
+    ///
+    /// * `Biconnected` - The blocks with their edges, the articulation points and the bridges of the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(5);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)] {
+    ///     g.add_edge(u, v);
+    ///     g.add_edge(v, u);
+    /// }
+    ///
+    /// let result = biconnected_components(&g);
+    /// assert_eq!(result.blocks, vec![vec![(0, 1), (0, 2), (1, 2)], vec![(2, 3)], vec![(3, 4)]]);
+    /// assert_eq!(result.articulation_points, vec![2, 3]);
+    /// ```
+    pub fn biconnected_components(g: &Graph) -> Biconnected {
+        let n = g.edges.len();
+        let mut search = Search {
+            disc: vec![usize::MAX; n],
+            low: vec![usize::MAX; n],
+            time: 0,
+            stack: Vec::new(),
+            blocks: Vec::new(),
+            is_cut: vec![false; n],
+            bridges: Vec::new(),
+        };
+        for u in 0..n {
+            if search.disc[u] == usize::MAX {
+                visit(g, u, None, &mut search); //Start a new DFS tree from every unvisited vertex
+            }
+        }
+
+        fn visit(g: &Graph, u: usize, parent: Option<usize>, s: &mut Search) {
+            s.disc[u] = s.time;
+            s.low[u] = s.time;
+            s.time += 1;
+            let mut children = 0;
+            let mut skipped_parent = false; //Only the tree edge itself is skipped, so parallel edges act as back edges
+            for &v in &g.edges[u] {
+                if v == u {
+                    continue; //Self-loops never affect biconnectivity
+                }
+                if Some(v) == parent && !skipped_parent {
+                    skipped_parent = true;
+                    continue;
+                }
+                if s.disc[v] == usize::MAX {
+                    //Tree edge
+                    children += 1;
+                    s.stack.push((u, v));
+                    visit(g, v, Some(u), s);
+                    s.low[u] = s.low[u].min(s.low[v]);
+                    if s.low[v] > s.disc[u] {
+                        s.bridges.push((u.min(v), u.max(v)));
+                    }
+                    if s.low[v] >= s.disc[u] {
+                        //u separates the subtree of v, so the edges above (u, v) on the stack form a block
+                        if parent.is_some() {
+                            s.is_cut[u] = true;
+                        }
+                        let mut block = Vec::new();
+                        while let Some((a, b)) = s.stack.pop() {
+                            block.push((a.min(b), a.max(b)));
+                            if (a, b) == (u, v) {
+                                break;
+                            }
+                        }
+                        block.sort();
+                        s.blocks.push(block);
+                    }
+                } else if s.disc[v] < s.disc[u] {
+                    //Back edge to an ancestor, the reverse copy of a tree edge is skipped above
+                    s.stack.push((u, v));
+                    s.low[u] = s.low[u].min(s.disc[v]);
+                }
+            }
+            //The root of a DFS tree is a cut vertex only when it has more than one child
+            if parent.is_none() && children > 1 {
+                s.is_cut[u] = true;
+            }
+        }
+
+        search.blocks.sort();
+        search.bridges.sort();
+        let articulation_points = (0..n).filter(|&u| search.is_cut[u]).collect();
+        Biconnected {
+            blocks: search.blocks,
+            articulation_points,
+            bridges: search.bridges,
+        }
+    }
+
+    /// Returns the bridges of an undirected graph as `(u, v)` pairs with `u < v` in sorted order.
+    pub fn bridges(g: &Graph) -> Vec<(usize, usize)> {
+        biconnected_components(g).bridges
+    }
+
+    /// Builds the block-cut tree of an undirected graph from its biconnected-component decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The decomposition returned by `biconnected_components`.
+    ///
+    /// # Returns
+    ///
+    /// * `BlockCutTree` - The vertex set of each block, the cut vertices and the block to cut vertex edges.
+    ///   For a disconnected graph this is a forest with one tree per connected component.
+    pub fn block_cut_tree(result: &Biconnected) -> BlockCutTree {
+        let mut blocks = Vec::new();
+        let mut edges = Vec::new();
+        for (i, block) in result.blocks.iter().enumerate() {
+            //Collect the distinct vertices touched by the edges of the block
+            let mut vertices: Vec<usize> = block.iter().flat_map(|&(u, v)| [u, v]).collect();
+            vertices.sort();
+            vertices.dedup();
+            for &u in &vertices {
+                if result.articulation_points.binary_search(&u).is_ok() {
+                    edges.push((i, u));
+                }
+            }
+            blocks.push(vertices);
+        }
+
+        BlockCutTree {
+            blocks,
+            cut_vertices: result.articulation_points.clone(),
+            edges,
+        }
+    }
+
+    /// Finds the 2-edge-connected components of an undirected graph.
+    /// These are the connected components left after removing every bridge, so no single edge failure disconnects them.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph represented as a symmetric adjacency list.
+    ///
+    /// # Returns
+    ///
+    /// * `components` - The components, each in sorted order, sorted like the output of `kosaraju_algorithm`.
+    pub fn two_edge_connected_components(g: &Graph) -> Vec<Vec<usize>> {
+        let n = g.edges.len();
+        let bridges = bridges(g);
+        let mut component = vec![usize::MAX; n];
+        let mut components = Vec::new();
+        for start in 0..n {
+            if component[start] != usize::MAX {
+                continue;
+            }
+            //Flood fill from start without crossing any bridge
+            let id = components.len();
+            let mut members = vec![start];
+            let mut stack = vec![start];
+            component[start] = id;
+            while let Some(u) = stack.pop() {
+                for &v in &g.edges[u] {
+                    if component[v] == usize::MAX && bridges.binary_search(&(u.min(v), u.max(v))).is_err() {
+                        component[v] = id;
+                        members.push(v);
+                        stack.push(v);
+                    }
+                }
+            }
+            members.sort();
+            components.push(members);
+        }
+        components.sort();
+        components
+    }
+
+    /// Decomposes an undirected graph entered by the user into biconnected and 2-edge-connected components.
+    ///
+    /// # Input
+    /// * `no_of_vertices` - Input the number of vertices in the graph
+    /// * `no_of_edges` - Input the number of edges in the graph
+    /// * `source` - The source vertex of an edge in the graph
+    /// * `destination` - The destination of an edge in the graph
+    ///
+    /// # Output
+    ///
+    /// Prints the articulation points, bridges, blocks, block-cut tree and 2-edge-connected components
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 5
+    ///Please Enter Number of edges in the graph : 5
+    ///Source : 0
+    ///Destination : 1
+    ///Source : 1
+    ///Destination : 2
+    ///Source : 2
+    ///Destination : 0
+    ///Source : 2
+    ///Destination : 3
+    ///Source : 3
+    ///Destination : 4
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///Articulation points: [2, 3]
+    ///Bridges: [(2, 3), (3, 4)]
+    ///Blocks: [[(0, 1), (0, 2), (1, 2)], [(2, 3)], [(3, 4)]]
+    ///Block vertices: [[0, 1, 2], [2, 3], [3, 4]]
+    ///Cut vertices: [2, 3]
+    ///Block-cut tree edges (block, cut vertex): [(0, 2), (1, 2), (1, 3), (2, 3)]
+    ///2-edge-connected components: [[0, 1, 2], [3], [4]]
+    /// ```
+    pub fn biconnected() {
+        //read the number of vertices from the console
+        let mut vertex = String::new();
+        println!("******Biconnected Components*********");
+        println!("****************************************************");
+        //get the number of vertices
+        print!("Please Enter Number of Vertices : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut vertex)
+            .expect("Enter valid number of vertices");
+        let vertices: usize = vertex.trim().parse().expect("Invalid input");
+        //get number of edges in the graph
+        let mut n_edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut n_edges).expect("Enter Valid Input");
+        let n_edges: i32 = n_edges.trim().parse().expect("Invalid input for edges");
+        //read the undirected edges, stored in both directions
+        let g = add_edges(vertices, n_edges);
+
+        let result = biconnected_components(&g);
+        let tree = block_cut_tree(&result);
+        println!("******************");
+        println!("Articulation points: {:?}", result.articulation_points);
+        println!("Bridges: {:?}", result.bridges);
+        println!("Blocks: {:?}", result.blocks);
+        println!("Block vertices: {:?}", tree.blocks);
+        println!("Cut vertices: {:?}", tree.cut_vertices);
+        println!("Block-cut tree edges (block, cut vertex): {:?}", tree.edges);
+        println!(
+            "2-edge-connected components: {:?}",
+            two_edge_connected_components(&g)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::biconnected::*;
+    use crate::list_of_algorithms::dfs::dfs::Graph;
+
+    fn undirected(vertices: usize, edges: &[(usize, usize)]) -> Graph {
+        let mut g = Graph::new(vertices);
+        for &(u, v) in edges {
+            g.add_edge(u, v);
+            g.add_edge(v, u);
+        }
+        g
+    }
+
+    #[test]
+    fn test_blocks_and_cut_vertices() {
+        let g = undirected(7, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3), (5, 6)]);
+        let result = biconnected_components(&g);
+
+        assert_eq!(
+            result.blocks,
+            vec![
+                vec![(0, 1), (0, 2), (1, 2)],
+                vec![(2, 3)],
+                vec![(3, 4), (3, 5), (4, 5)],
+                vec![(5, 6)],
+            ]
+        );
+        assert_eq!(result.articulation_points, vec![2, 3, 5]);
+        assert_eq!(result.bridges, vec![(2, 3), (5, 6)]);
+    }
+
+    #[test]
+    fn test_block_cut_tree() {
+        let g = undirected(5, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)]);
+        let tree = block_cut_tree(&biconnected_components(&g));
+
+        assert_eq!(tree.blocks, vec![vec![0, 1, 2], vec![2, 3], vec![3, 4]]);
+        assert_eq!(tree.cut_vertices, vec![2, 3]);
+        assert_eq!(tree.edges, vec![(0, 2), (1, 2), (1, 3), (2, 3)]);
+    }
+
+    #[test]
+    fn test_parallel_edges_are_not_bridges() {
+        //Two parallel links between 0 and 1, a single link between 1 and 2
+        let g = undirected(3, &[(0, 1), (0, 1), (1, 2)]);
+
+        assert_eq!(bridges(&g), vec![(1, 2)]);
+        assert_eq!(biconnected_components(&g).articulation_points, vec![1]);
+        assert_eq!(two_edge_connected_components(&g), vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn test_two_edge_connected_components() {
+        //Two cycles sharing vertex 2 stay 2-edge-connected, vertex 5 hangs off a bridge, 6 is isolated
+        let g = undirected(7, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 5)]);
+
+        assert_eq!(
+            two_edge_connected_components(&g),
+            vec![vec![0, 1, 2, 3, 4], vec![5], vec![6]]
+        );
+        assert_eq!(biconnected_components(&g).articulation_points, vec![2, 4]);
+    }
+}
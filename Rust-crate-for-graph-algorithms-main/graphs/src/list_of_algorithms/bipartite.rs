@@ -0,0 +1,217 @@
+pub mod bipartite {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::bfs::bfs::{add_edges, Graph};
+    use std::io::{stdin, stdout, Write};
+
+    /// The outcome of a bipartiteness check.
+    #[derive(Debug, PartialEq)]
+    pub enum Bipartiteness {
+        /// The graph is bipartite. Every edge joins a vertex of `left` to a vertex of `right`, both in sorted order.
+        Bipartite { left: Vec<usize>, right: Vec<usize> },
+        /// The graph is not bipartite. The vertices of an odd cycle in order, where the last vertex is joined back to the first.
+        /// A self-loop is reported as a cycle of length one.
+        OddCycle(Vec<usize>),
+    }
+
+    /// Checks whether an undirected graph is bipartite by 2-colouring it with Breadth-First Search.
+    /// Every component is coloured by the parity of the BFS depth from its smallest vertex, with even depths on the left.
+    /// An edge between two vertices of the same depth closes an odd cycle through their lowest common ancestor in the BFS tree,
+    /// which is returned as a witness.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`.
+    ///
+    /// # Returns
+    ///
+    /// * `Bipartiteness` - Either the two sides of the partition or an odd cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(1, 2);
+    /// g.add_edge(2, 3);
+    /// assert_eq!(
+    ///     check_bipartite(&g),
+    ///     Bipartiteness::Bipartite { left: vec![0, 2], right: vec![1, 3] }
+    /// );
+    ///
+    /// g.add_edge(2, 0);
+    /// assert_eq!(check_bipartite(&g), Bipartiteness::OddCycle(vec![0, 1, 2]));
+    /// ```
+    pub fn check_bipartite(g: &Graph) -> Bipartiteness {
+        let n = g.edges.len();
+        let mut parent = vec![usize::MAX; n];
+        let mut depth = vec![usize::MAX; n]; //usize::MAX marks a vertex that is not coloured yet
+
+        for start in 0..n {
+            if depth[start] != usize::MAX {
+                continue;
+            }
+            let order = g.bfs_tree(start, &mut parent, &mut depth);
+            for &u in &order {
+                for &v in &g.edges[u] {
+                    //BFS depths of adjacent vertices differ by at most one, so equal colours mean equal depths
+                    if depth[u] == depth[v] {
+                        return Bipartiteness::OddCycle(odd_cycle(u, v, &parent));
+                    }
+                }
+            }
+        }
+
+        let (left, right) = (0..n).partition(|&u| depth[u] % 2 == 0);
+        Bipartiteness::Bipartite { left, right }
+    }
+
+    //Builds the odd cycle closed by the edge u-v, where u and v have the same BFS depth
+    fn odd_cycle(u: usize, v: usize, parent: &[usize]) -> Vec<usize> {
+        let mut left = vec![u];
+        let mut right = vec![v];
+        let (mut a, mut b) = (u, v);
+        //Climb both paths one level at a time until they meet at the lowest common ancestor
+        while a != b {
+            a = parent[a];
+            b = parent[b];
+            left.push(a);
+            right.push(b);
+        }
+        right.pop(); //The common ancestor is already on the left path
+        left.reverse();
+        //The cycle runs from the ancestor down to u, across to v and back up
+        left.extend(right);
+        left
+    }
+
+    /// Checks whether a graph entered by the user is bipartite.
+    ///
+    /// # Input
+    /// * `no_of_vertices` - Input the number of vertices in the graph
+    /// * `no_of_edges` - Input the number of edges in the graph
+    /// * `source` - The source vertex of an edge in the graph
+    /// * `destination` - The destination of an edge in the graph
+    ///
+    /// # Output
+    ///
+    /// Prints the two sides of the graph, or an odd cycle when the graph is not bipartite
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 4
+    ///Please Enter Number of edges in the graph : 4
+    ///Source : 0
+    ///Destination : 1
+    ///Source : 1
+    ///Destination : 2
+    ///Source : 2
+    ///Destination : 3
+    ///Source : 3
+    ///Destination : 1
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///The graph is not bipartite, odd cycle : [1, 2, 3]
+    /// ```
+    pub fn bipartite() {
+        //read the number of vertices from the console
+        let mut vertex = String::new();
+        println!("*****Bipartiteness Check********");
+        println!("****************************************************");
+        print!("Please Enter Number of Vertices : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut vertex)
+            .expect("Enter valid number of vertices");
+        let vertices: usize = vertex.trim().parse().expect("Invalid input");
+        //get number of edges in the graph
+        let mut n_edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut n_edges).expect("Enter Valid Input");
+        let n_edges: i32 = n_edges.trim().parse().expect("Invalid input for edges");
+        //assign the vertices to each edge from the console
+        let g = add_edges(vertices, n_edges);
+
+        println!("******************");
+        match check_bipartite(&g) {
+            Bipartiteness::Bipartite { left, right } => {
+                println!("The graph is bipartite");
+                println!("Left side : {:?}", left);
+                println!("Right side : {:?}", right);
+            }
+            Bipartiteness::OddCycle(cycle) => {
+                println!("The graph is not bipartite, odd cycle : {:?}", cycle)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bipartite::*;
+    use crate::list_of_algorithms::bfs::bfs::Graph;
+
+    fn check_cycle(g: &Graph, cycle: &[usize]) {
+        //The witness is a closed walk of odd length along edges of the graph
+        assert_eq!(cycle.len() % 2, 1);
+        for i in 0..cycle.len() {
+            let (u, v) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+            assert!(g.edges[u].contains(&v));
+        }
+    }
+
+    #[test]
+    fn test_bipartite_components() {
+        //An even cycle and a separate path
+        let mut g = Graph::new(7);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 3);
+        g.add_edge(3, 0);
+        g.add_edge(4, 5);
+        g.add_edge(6, 5);
+        assert_eq!(
+            check_bipartite(&g),
+            Bipartiteness::Bipartite {
+                left: vec![0, 2, 4, 6],
+                right: vec![1, 3, 5]
+            }
+        );
+        assert_eq!(
+            check_bipartite(&Graph::new(0)),
+            Bipartiteness::Bipartite {
+                left: vec![],
+                right: vec![]
+            }
+        );
+    }
+
+    #[test]
+    fn test_odd_cycle_witness() {
+        //A 5-cycle hanging off a path
+        let mut g = Graph::new(7);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 3);
+        g.add_edge(3, 4);
+        g.add_edge(4, 5);
+        g.add_edge(5, 6);
+        g.add_edge(6, 2);
+        match check_bipartite(&g) {
+            Bipartiteness::OddCycle(cycle) => {
+                assert_eq!(cycle.len(), 5);
+                check_cycle(&g, &cycle);
+            }
+            other => panic!("expected an odd cycle, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_self_loop() {
+        let mut g = Graph::new(2);
+        g.add_edge(0, 1);
+        g.edges[1].push(1);
+        assert_eq!(check_bipartite(&g), Bipartiteness::OddCycle(vec![1]));
+    }
+}
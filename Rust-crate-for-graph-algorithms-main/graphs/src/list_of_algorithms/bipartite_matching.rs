@@ -0,0 +1,327 @@
+pub mod bipartite_matching {
+    //Importng necessary libraries
+    use std::{
+        collections::VecDeque,
+        io::{stdin, stdout, Write},
+    };
+
+    /// A bipartite graph with `left` vertices on one side and `right` vertices on the other.
+    /// Both sides are numbered from 0.
+    pub struct BipartiteGraph {
+        /// The right vertices adjacent to every left vertex.
+        pub edges: Vec<Vec<usize>>,
+        /// The number of vertices on the left side.
+        pub left: usize,
+        /// The number of vertices on the right side.
+        pub right: usize,
+    }
+
+    impl BipartiteGraph {
+        /// Constructs a bipartite graph with no edges.
+        pub fn new(left: usize, right: usize) -> Self {
+            BipartiteGraph {
+                edges: vec![Vec::new(); left],
+                left,
+                right,
+            }
+        }
+
+        /// Adds an edge between left vertex `l` and right vertex `r`.
+        pub fn add_edge(&mut self, l: usize, r: usize) {
+            self.edges[l].push(r);
+        }
+    }
+
+    /// A maximum matching of a bipartite graph together with a minimum vertex cover of the same size.
+    pub struct Matching {
+        /// The matched pairs `(left, right)` in order of the left vertex.
+        pub pairs: Vec<(usize, usize)>,
+        /// The left vertices of a minimum vertex cover in sorted order.
+        pub left_cover: Vec<usize>,
+        /// The right vertices of a minimum vertex cover in sorted order.
+        pub right_cover: Vec<usize>,
+    }
+
+    /// The Hopcroft-Karp algorithm finds a maximum-cardinality matching of a bipartite graph in O(E * sqrt(V)) time.
+    /// Every phase layers the graph with a Breadth-First Search from the free left vertices and then augments along
+    /// vertex-disjoint shortest alternating paths with Depth-First Search.
+    ///
+    /// The minimum vertex cover is built from the final matching with König's theorem, which proves the matching is maximum:
+    /// every edge has an endpoint in the cover and the cover has exactly one vertex per matched pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - The bipartite graph.
+    ///
+    /// # Returns
+    ///
+    /// * `Matching` - The matched pairs and a minimum vertex cover.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = BipartiteGraph::new(3, 3);
+    /// g.add_edge(0, 0);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(1, 0);
+    /// g.add_edge(2, 0);
+    ///
+    /// let matching = hopcroft_karp(&g);
+    /// assert_eq!(matching.pairs, vec![(0, 1), (1, 0)]);
+    /// assert_eq!((matching.left_cover, matching.right_cover), (vec![0], vec![0]));
+    /// ```
+    pub fn hopcroft_karp(g: &BipartiteGraph) -> Matching {
+        let mut match_left = vec![usize::MAX; g.left]; //right partner of every left vertex, usize::MAX when free
+        let mut match_right = vec![usize::MAX; g.right]; //left partner of every right vertex, usize::MAX when free
+
+        loop {
+            //Breadth-First Search from all free left vertices, dist is the layer of every left vertex
+            let mut dist = vec![usize::MAX; g.left];
+            let mut queue = VecDeque::new();
+            for l in 0..g.left {
+                if match_left[l] == usize::MAX {
+                    dist[l] = 0;
+                    queue.push_back(l);
+                }
+            }
+            //The layer of the first free right vertex reached bounds the length of the shortest augmenting paths
+            let mut limit = usize::MAX;
+            while let Some(l) = queue.pop_front() {
+                for &r in &g.edges[l] {
+                    let next = match_right[r];
+                    if next == usize::MAX {
+                        limit = limit.min(dist[l]);
+                    } else if dist[next] == usize::MAX && dist[l] < limit {
+                        dist[next] = dist[l] + 1;
+                        queue.push_back(next);
+                    }
+                }
+            }
+            if limit == usize::MAX {
+                break;
+            }
+
+            //Depth-First Search along the layers, augmenting vertex-disjoint shortest paths
+            for l in 0..g.left {
+                if match_left[l] == usize::MAX {
+                    augment(g, l, limit, &mut dist, &mut match_left, &mut match_right);
+                }
+            }
+        }
+
+        fn augment(
+            g: &BipartiteGraph,
+            l: usize,
+            limit: usize,
+            dist: &mut [usize],
+            match_left: &mut [usize],
+            match_right: &mut [usize],
+        ) -> bool {
+            for &r in &g.edges[l] {
+                let next = match_right[r];
+                //Only paths ending at the limit layer are shortest
+                let free_at_limit = next == usize::MAX && dist[l] == limit;
+                if free_at_limit
+                    || (next != usize::MAX
+                        && dist[l] < limit
+                        && dist[next] == dist[l] + 1
+                        && augment(g, next, limit, dist, match_left, match_right))
+                {
+                    match_left[l] = r;
+                    match_right[r] = l;
+                    return true;
+                }
+            }
+            dist[l] = usize::MAX; //No augmenting path goes through l in this phase
+            false
+        }
+
+        //König's theorem: Z is everything reachable from the free left vertices by alternating paths
+        let mut left_in_z = vec![false; g.left];
+        let mut right_in_z = vec![false; g.right];
+        let mut queue = VecDeque::new();
+        for l in 0..g.left {
+            if match_left[l] == usize::MAX {
+                left_in_z[l] = true;
+                queue.push_back(l);
+            }
+        }
+        while let Some(l) = queue.pop_front() {
+            for &r in &g.edges[l] {
+                if !right_in_z[r] {
+                    right_in_z[r] = true;
+                    let next = match_right[r]; //r is matched, otherwise the matching would not be maximum
+                    if next != usize::MAX && !left_in_z[next] {
+                        left_in_z[next] = true;
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        //The cover is the left vertices outside Z and the right vertices inside Z
+        Matching {
+            pairs: (0..g.left)
+                .filter(|&l| match_left[l] != usize::MAX)
+                .map(|l| (l, match_left[l]))
+                .collect(),
+            left_cover: (0..g.left).filter(|&l| !left_in_z[l]).collect(),
+            right_cover: (0..g.right).filter(|&r| right_in_z[r]).collect(),
+        }
+    }
+
+    /// Finds a maximum matching of a bipartite graph entered by the user.
+    ///
+    /// # Input
+    /// * `no_of_left_vertices` - Input the number of vertices on the left side
+    /// * `no_of_right_vertices` - Input the number of vertices on the right side
+    /// * `no_of_edges` - Input the number of edges in the graph
+    /// * `left` - The left vertex of an edge
+    /// * `right` - The right vertex of an edge
+    ///
+    /// # Output
+    ///
+    /// Prints the matched pairs and a minimum vertex cover
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Left Vertices : 3
+    ///Please Enter Number of Right Vertices : 3
+    ///Please Enter Number of edges in the graph : 4
+    ///Left : 0
+    ///Right : 0
+    ///Left : 0
+    ///Right : 1
+    ///Left : 1
+    ///Right : 0
+    ///Left : 2
+    ///Right : 0
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///Maximum matching size : 2
+    ///Matched pairs (left, right) : [(0, 1), (1, 0)]
+    ///Minimum vertex cover : left [0], right [0]
+    /// ```
+    pub fn matching() {
+        //read the number of vertices on both sides from the console
+        let mut left = String::new();
+        let mut right = String::new();
+        println!("******Bipartite Matching*********");
+        println!("****************************************************");
+        print!("Please Enter Number of Left Vertices : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut left)
+            .expect("Enter valid number of vertices");
+        let left: usize = left.trim().parse().expect("Invalid input");
+        print!("Please Enter Number of Right Vertices : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut right)
+            .expect("Enter valid number of vertices");
+        let right: usize = right.trim().parse().expect("Invalid input");
+        //get number of edges in the graph
+        let mut n_edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut n_edges).expect("Enter Valid Input");
+        let n_edges: i32 = n_edges.trim().parse().expect("Invalid input for edges");
+
+        let mut g = BipartiteGraph::new(left, right);
+        for _i in 0..n_edges {
+            //intialize left and right vertex
+            let mut l = String::new();
+            let mut r = String::new();
+            print!("Left : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut l)
+                .expect("Please Enter Valid Input for .");
+            let l: usize = l.trim().parse().expect("Invalid input for left vertex");
+            print!("Right : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut r)
+                .expect("Please Enter Valid Input for .");
+            let r: usize = r.trim().parse().expect("Invalid input for right vertex");
+            g.add_edge(l, r);
+        }
+
+        let result = hopcroft_karp(&g);
+        println!("******************");
+        println!("Maximum matching size : {}", result.pairs.len());
+        println!("Matched pairs (left, right) : {:?}", result.pairs);
+        println!(
+            "Minimum vertex cover : left {:?}, right {:?}",
+            result.left_cover, result.right_cover
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bipartite_matching::*;
+
+    fn check_cover(g: &BipartiteGraph, m: &Matching) {
+        //The cover touches every edge and is as large as the matching
+        for l in 0..g.left {
+            for &r in &g.edges[l] {
+                assert!(m.left_cover.contains(&l) || m.right_cover.contains(&r));
+            }
+        }
+        assert_eq!(m.left_cover.len() + m.right_cover.len(), m.pairs.len());
+    }
+
+    #[test]
+    fn test_perfect_matching() {
+        let mut g = BipartiteGraph::new(4, 4);
+        g.add_edge(0, 0);
+        g.add_edge(0, 1);
+        g.add_edge(1, 0);
+        g.add_edge(1, 2);
+        g.add_edge(2, 1);
+        g.add_edge(2, 3);
+        g.add_edge(3, 2);
+
+        let m = hopcroft_karp(&g);
+        assert_eq!(m.pairs.len(), 4);
+        check_cover(&g, &m);
+        let mut rights: Vec<usize> = m.pairs.iter().map(|&(_, r)| r).collect();
+        rights.sort();
+        assert_eq!(rights, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_maximum_matching_needs_augmenting_path() {
+        //The greedy choice 0-0 must be undone to match vertex 1
+        let mut g = BipartiteGraph::new(3, 3);
+        g.add_edge(0, 0);
+        g.add_edge(0, 1);
+        g.add_edge(1, 0);
+        g.add_edge(2, 0);
+
+        let m = hopcroft_karp(&g);
+        assert_eq!(m.pairs, vec![(0, 1), (1, 0)]);
+        assert_eq!(m.left_cover, vec![0]);
+        assert_eq!(m.right_cover, vec![0]);
+        check_cover(&g, &m);
+    }
+
+    #[test]
+    fn test_empty_and_unbalanced() {
+        let g = BipartiteGraph::new(2, 0);
+        let m = hopcroft_karp(&g);
+        assert!(m.pairs.is_empty());
+        assert!(m.left_cover.is_empty() && m.right_cover.is_empty());
+
+        let mut g = BipartiteGraph::new(1, 5);
+        for r in 0..5 {
+            g.add_edge(0, r);
+        }
+        let m = hopcroft_karp(&g);
+        assert_eq!(m.pairs.len(), 1);
+        assert_eq!(m.left_cover, vec![0]);
+        check_cover(&g, &m);
+    }
+}
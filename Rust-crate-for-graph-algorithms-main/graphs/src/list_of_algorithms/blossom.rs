@@ -0,0 +1,890 @@
+pub mod blossom {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::bfs::bfs;
+    use crate::list_of_algorithms::dijkstras::dijkstras::{self, add_edges};
+    use std::{
+        collections::VecDeque,
+        io::{stdin, stdout, Write},
+    };
+
+    //Marks a missing vertex, edge endpoint or blossom
+    const NONE: usize = usize::MAX;
+
+    /// A matching of a weighted graph.
+    pub struct WeightedMatching {
+        /// The matched pairs `(u, v)` with `u < v`, in sorted order.
+        pub pairs: Vec<(usize, usize)>,
+        /// The sum of the weights of the matched edges.
+        pub total_weight: i32,
+    }
+
+    //State of one augmenting path search of the unweighted algorithm
+    struct PathSearch<'a> {
+        g: &'a bfs::Graph,
+        mate: Vec<usize>,          //partner of every vertex, NONE when free
+        parent: Vec<usize>,        //previous vertex on the alternating tree, set for odd (outer-matched) vertices
+        base: Vec<usize>,          //base of the blossom containing every vertex
+        used: Vec<bool>,           //even vertices of the alternating tree
+        queue: VecDeque<usize>,
+    }
+
+    impl PathSearch<'_> {
+        //Lowest common ancestor of the blossoms of a and b in the alternating tree
+        fn lca(&self, mut a: usize, mut b: usize) -> usize {
+            let mut seen = vec![false; self.mate.len()];
+            loop {
+                a = self.base[a];
+                seen[a] = true;
+                if self.mate[a] == NONE {
+                    break; //Reached the root
+                }
+                a = self.parent[self.mate[a]];
+            }
+            loop {
+                b = self.base[b];
+                if seen[b] {
+                    return b;
+                }
+                b = self.parent[self.mate[b]];
+            }
+        }
+
+        //Marks the blossom vertices on the path from v up to the base b and links them back through child
+        fn mark_path(&mut self, mut v: usize, b: usize, mut child: usize, in_blossom: &mut [bool]) {
+            while self.base[v] != b {
+                in_blossom[self.base[v]] = true;
+                in_blossom[self.base[self.mate[v]]] = true;
+                self.parent[v] = child;
+                child = self.mate[v];
+                v = self.parent[self.mate[v]];
+            }
+        }
+
+        //Breadth-First Search for an augmenting path from root, returns its free end or NONE
+        fn find_path(&mut self, root: usize) -> usize {
+            let n = self.mate.len();
+            self.used = vec![false; n];
+            self.parent = vec![NONE; n];
+            self.base = (0..n).collect();
+            self.queue.clear();
+            self.used[root] = true;
+            self.queue.push_back(root);
+
+            while let Some(v) = self.queue.pop_front() {
+                for &to in &self.g.edges[v] {
+                    if self.base[v] == self.base[to] || self.mate[v] == to {
+                        continue; //Edge inside a blossom, self-loop or matched edge
+                    }
+                    if to == root || (self.mate[to] != NONE && self.parent[self.mate[to]] != NONE) {
+                        //Both ends are even, so the edge closes an odd cycle: contract it into its base
+                        let current_base = self.lca(v, to);
+                        let mut in_blossom = vec![false; n];
+                        self.mark_path(v, current_base, to, &mut in_blossom);
+                        self.mark_path(to, current_base, v, &mut in_blossom);
+                        for i in 0..n {
+                            if in_blossom[self.base[i]] {
+                                self.base[i] = current_base;
+                                if !self.used[i] {
+                                    self.used[i] = true;
+                                    self.queue.push_back(i);
+                                }
+                            }
+                        }
+                    } else if self.parent[to] == NONE {
+                        self.parent[to] = v;
+                        if self.mate[to] == NONE {
+                            return to; //A free vertex ends an augmenting path
+                        }
+                        let next = self.mate[to];
+                        self.used[next] = true;
+                        self.queue.push_back(next);
+                    }
+                }
+            }
+            NONE
+        }
+    }
+
+    /// Edmonds' blossom algorithm finds a maximum-cardinality matching of a general undirected graph in O(V^3) time.
+    /// Augmenting paths are searched with Breadth-First Search, and every odd cycle (blossom) met on the way is
+    /// contracted into its base so that the search can continue through it.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Self-loops are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `pairs` - The matched pairs `(u, v)` with `u < v`, in sorted order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //A triangle 0-1-2 with a tail 2-3 is not bipartite, yet two pairs can still be matched
+    /// let mut g = Graph::new(4);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(1, 2);
+    /// g.add_edge(2, 0);
+    /// g.add_edge(2, 3);
+    ///
+    /// assert_eq!(maximum_matching(&g), vec![(0, 1), (2, 3)]);
+    /// ```
+    pub fn maximum_matching(g: &bfs::Graph) -> Vec<(usize, usize)> {
+        let n = g.edges.len();
+        let mut search = PathSearch {
+            g,
+            mate: vec![NONE; n],
+            parent: Vec::new(),
+            base: Vec::new(),
+            used: Vec::new(),
+            queue: VecDeque::new(),
+        };
+        for root in 0..n {
+            if search.mate[root] != NONE {
+                continue;
+            }
+            //Flip the matching along the augmenting path ending at v, if one was found
+            let mut v = search.find_path(root);
+            while v != NONE {
+                let pv = search.parent[v];
+                let next = search.mate[pv];
+                search.mate[v] = pv;
+                search.mate[pv] = v;
+                v = next;
+            }
+        }
+
+        let mate = search.mate;
+        (0..n)
+            .filter(|&u| mate[u] != NONE && u < mate[u])
+            .map(|u| (u, mate[u]))
+            .collect()
+    }
+
+    //State of the primal-dual weighted matching algorithm.
+    //Vertices are 0..n and non-trivial blossoms n..2n. Edge k has endpoints 2k and 2k + 1.
+    struct WeightedSearch {
+        n: usize,
+        edges: Vec<(usize, usize, i64)>,
+        endpoint: Vec<usize>,              //vertex at every edge endpoint
+        neighbend: Vec<Vec<usize>>,        //remote endpoints of the edges of every vertex
+        mate: Vec<usize>,                  //remote endpoint of the matched edge of every vertex
+        label: Vec<i32>,                   //0 free, 1 S (even), 2 T (odd), 5 temporarily marked
+        labelend: Vec<usize>,              //endpoint through which a vertex or blossom got its label
+        inblossom: Vec<usize>,             //top-level blossom containing every vertex
+        blossomparent: Vec<usize>,
+        blossomchilds: Vec<Vec<usize>>,    //sub-blossoms of every blossom in cyclic order starting at the base
+        blossombase: Vec<usize>,
+        blossomendps: Vec<Vec<usize>>,     //endpoints of the edges connecting consecutive sub-blossoms
+        bestedge: Vec<usize>,              //least-slack edge to an S-blossom
+        blossombestedges: Vec<Option<Vec<usize>>>,
+        unusedblossoms: Vec<usize>,
+        dualvar: Vec<i64>,
+        allowedge: Vec<bool>,              //edges known to have zero slack
+        queue: Vec<usize>,                 //S-vertices still to be scanned
+    }
+
+    impl WeightedSearch {
+        fn slack(&self, k: usize) -> i64 {
+            let (i, j, w) = self.edges[k];
+            self.dualvar[i] + self.dualvar[j] - 2 * w
+        }
+
+        //All vertices contained in blossom b
+        fn leaves(&self, b: usize) -> Vec<usize> {
+            if b < self.n {
+                return vec![b];
+            }
+            let mut result = Vec::new();
+            for &t in &self.blossomchilds[b] {
+                result.extend(self.leaves(t));
+            }
+            result
+        }
+
+        //Labels the top-level blossom of w with t, reached through endpoint p
+        fn assign_label(&mut self, w: usize, t: i32, p: usize) {
+            let b = self.inblossom[w];
+            self.label[w] = t;
+            self.label[b] = t;
+            self.labelend[w] = p;
+            self.labelend[b] = p;
+            self.bestedge[w] = NONE;
+            self.bestedge[b] = NONE;
+            if t == 1 {
+                //b became an S-blossom, all its vertices need scanning
+                let leaves = self.leaves(b);
+                self.queue.extend(leaves);
+            } else if t == 2 {
+                //b became a T-blossom, its mate becomes an S-blossom
+                let base = self.blossombase[b];
+                let m = self.mate[base];
+                self.assign_label(self.endpoint[m], 1, m ^ 1);
+            }
+        }
+
+        //Traces back from v and w to find a new blossom (returns its base) or an augmenting path (returns NONE)
+        fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+            let mut path = Vec::new();
+            let mut base = NONE;
+            while v != NONE || w != NONE {
+                let mut b = self.inblossom[v];
+                if self.label[b] & 4 != 0 {
+                    base = self.blossombase[b];
+                    break;
+                }
+                path.push(b);
+                self.label[b] = 5;
+                if self.labelend[b] == NONE {
+                    v = NONE; //Reached a root
+                } else {
+                    v = self.endpoint[self.labelend[b]];
+                    b = self.inblossom[v];
+                    v = self.endpoint[self.labelend[b]];
+                }
+                if w != NONE {
+                    std::mem::swap(&mut v, &mut w);
+                }
+            }
+            for b in path {
+                self.label[b] = 1;
+            }
+            base
+        }
+
+        //Creates a new blossom with the given base, closed by edge k between two S-vertices
+        fn add_blossom(&mut self, base: usize, k: usize) {
+            let (mut v, mut w, _) = self.edges[k];
+            let bb = self.inblossom[base];
+            let mut bv = self.inblossom[v];
+            let mut bw = self.inblossom[w];
+            let b = self.unusedblossoms.pop().expect("no unused blossom");
+            self.blossombase[b] = base;
+            self.blossomparent[b] = NONE;
+            self.blossomparent[bb] = b;
+            let mut path = Vec::new();
+            let mut endps = Vec::new();
+            //Walk from v back to the base
+            while bv != bb {
+                self.blossomparent[bv] = b;
+                path.push(bv);
+                endps.push(self.labelend[bv]);
+                v = self.endpoint[self.labelend[bv]];
+                bv = self.inblossom[v];
+            }
+            path.push(bb);
+            path.reverse();
+            endps.reverse();
+            endps.push(2 * k);
+            //Walk from w back to the base
+            while bw != bb {
+                self.blossomparent[bw] = b;
+                path.push(bw);
+                endps.push(self.labelend[bw] ^ 1);
+                w = self.endpoint[self.labelend[bw]];
+                bw = self.inblossom[w];
+            }
+            self.label[b] = 1;
+            self.labelend[b] = self.labelend[bb];
+            self.dualvar[b] = 0;
+            self.blossomchilds[b] = path.clone();
+            self.blossomendps[b] = endps;
+            for v in self.leaves(b) {
+                if self.label[self.inblossom[v]] == 2 {
+                    self.queue.push(v); //T-vertices inside the blossom become S-vertices
+                }
+                self.inblossom[v] = b;
+            }
+
+            //Compute the least-slack edges from the new blossom to every other S-blossom
+            let mut bestedgeto = vec![NONE; 2 * self.n];
+            for &bv in &path {
+                let nblists: Vec<Vec<usize>> = match self.blossombestedges[bv].take() {
+                    Some(list) => vec![list],
+                    None => self
+                        .leaves(bv)
+                        .iter()
+                        .map(|&v| self.neighbend[v].iter().map(|&p| p / 2).collect())
+                        .collect(),
+                };
+                for nblist in nblists {
+                    for k in nblist {
+                        //j is the endpoint outside the new blossom
+                        let (i, j, _) = self.edges[k];
+                        let j = if self.inblossom[j] == b { i } else { j };
+                        let bj = self.inblossom[j];
+                        if bj != b
+                            && self.label[bj] == 1
+                            && (bestedgeto[bj] == NONE || self.slack(k) < self.slack(bestedgeto[bj]))
+                        {
+                            bestedgeto[bj] = k;
+                        }
+                    }
+                }
+                self.bestedge[bv] = NONE;
+            }
+            let best: Vec<usize> = bestedgeto.into_iter().filter(|&k| k != NONE).collect();
+            self.bestedge[b] = NONE;
+            for &k in &best {
+                if self.bestedge[b] == NONE || self.slack(k) < self.slack(self.bestedge[b]) {
+                    self.bestedge[b] = k;
+                }
+            }
+            self.blossombestedges[b] = Some(best);
+        }
+
+        //Expands blossom b, relabelling its sub-blossoms when it is expanded in the middle of a stage
+        fn expand_blossom(&mut self, b: usize, endstage: bool) {
+            let childs = self.blossomchilds[b].clone();
+            for &s in &childs {
+                self.blossomparent[s] = NONE;
+                if s < self.n {
+                    self.inblossom[s] = s;
+                } else if endstage && self.dualvar[s] == 0 {
+                    self.expand_blossom(s, endstage);
+                } else {
+                    for v in self.leaves(s) {
+                        self.inblossom[v] = s;
+                    }
+                }
+            }
+
+            if !endstage && self.label[b] == 2 {
+                //b was a T-blossom, relabel the sub-blossoms on the even-length path through it
+                let endps = self.blossomendps[b].clone();
+                let len = childs.len() as isize;
+                let at = |j: isize| j.rem_euclid(len) as usize;
+                let entrychild = self.inblossom[self.endpoint[self.labelend[b] ^ 1]];
+                let mut j = childs.iter().position(|&c| c == entrychild).expect("entry child") as isize;
+                let (jstep, endptrick): (isize, usize) = if j & 1 == 1 {
+                    j -= len;
+                    (1, 0)
+                } else {
+                    (-1, 1)
+                };
+                let mut p = self.labelend[b];
+                while j != 0 {
+                    let q = endps[at(j - endptrick as isize)];
+                    self.label[self.endpoint[p ^ 1]] = 0;
+                    self.label[self.endpoint[q ^ endptrick ^ 1]] = 0;
+                    self.assign_label(self.endpoint[p ^ 1], 2, p);
+                    self.allowedge[q / 2] = true;
+                    j += jstep;
+                    p = endps[at(j - endptrick as isize)] ^ endptrick;
+                    self.allowedge[p / 2] = true;
+                    j += jstep;
+                }
+                let bv = childs[at(j)];
+                self.label[self.endpoint[p ^ 1]] = 2;
+                self.label[bv] = 2;
+                self.labelend[self.endpoint[p ^ 1]] = p;
+                self.labelend[bv] = p;
+                self.bestedge[bv] = NONE;
+                j += jstep;
+                while childs[at(j)] != entrychild {
+                    let bv = childs[at(j)];
+                    if self.label[bv] == 1 {
+                        j += jstep;
+                        continue; //Already reached as an S-blossom through a different edge
+                    }
+                    let labelled = self.leaves(bv).into_iter().find(|&v| self.label[v] != 0);
+                    if let Some(v) = labelled {
+                        //bv was reached from outside the blossom, label it T through that edge
+                        self.label[v] = 0;
+                        let m = self.mate[self.blossombase[bv]];
+                        self.label[self.endpoint[m]] = 0;
+                        self.assign_label(v, 2, self.labelend[v]);
+                    }
+                    j += jstep;
+                }
+            }
+
+            self.label[b] = -1;
+            self.labelend[b] = NONE;
+            self.blossomchilds[b] = Vec::new();
+            self.blossomendps[b] = Vec::new();
+            self.blossombase[b] = NONE;
+            self.blossombestedges[b] = None;
+            self.bestedge[b] = NONE;
+            self.unusedblossoms.push(b);
+        }
+
+        //Swaps matched and unmatched edges on the even path from vertex v to the base of blossom b
+        fn augment_blossom(&mut self, b: usize, v: usize) {
+            let mut t = v;
+            while self.blossomparent[t] != b {
+                t = self.blossomparent[t];
+            }
+            if t >= self.n {
+                self.augment_blossom(t, v);
+            }
+            let len = self.blossomchilds[b].len() as isize;
+            let at = |j: isize| j.rem_euclid(len) as usize;
+            let i = self.blossomchilds[b].iter().position(|&c| c == t).expect("child") as isize;
+            let mut j = i;
+            let (jstep, endptrick): (isize, usize) = if i & 1 == 1 {
+                j -= len;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+            while j != 0 {
+                j += jstep;
+                let t = self.blossomchilds[b][at(j)];
+                let p = self.blossomendps[b][at(j - endptrick as isize)] ^ endptrick;
+                if t >= self.n {
+                    self.augment_blossom(t, self.endpoint[p]);
+                }
+                j += jstep;
+                let t = self.blossomchilds[b][at(j)];
+                if t >= self.n {
+                    self.augment_blossom(t, self.endpoint[p ^ 1]);
+                }
+                self.mate[self.endpoint[p]] = p ^ 1;
+                self.mate[self.endpoint[p ^ 1]] = p;
+            }
+            //Rotate the blossom so that the sub-blossom containing v becomes the base
+            self.blossomchilds[b].rotate_left(i as usize);
+            self.blossomendps[b].rotate_left(i as usize);
+            self.blossombase[b] = self.blossombase[self.blossomchilds[b][0]];
+        }
+
+        //Augments the matching along the path through edge k between two S-vertices
+        fn augment_matching(&mut self, k: usize) {
+            let (v, w, _) = self.edges[k];
+            for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+                loop {
+                    let bs = self.inblossom[s];
+                    if bs >= self.n {
+                        self.augment_blossom(bs, s);
+                    }
+                    self.mate[s] = p;
+                    if self.labelend[bs] == NONE {
+                        break; //Reached a free root
+                    }
+                    let t = self.endpoint[self.labelend[bs]];
+                    let bt = self.inblossom[t];
+                    s = self.endpoint[self.labelend[bt]];
+                    let j = self.endpoint[self.labelend[bt] ^ 1];
+                    if bt >= self.n {
+                        self.augment_blossom(bt, j);
+                    }
+                    self.mate[j] = self.labelend[bt];
+                    p = self.labelend[bt] ^ 1;
+                }
+            }
+        }
+    }
+
+    /// Finds a maximum-weight matching of a weighted undirected graph with the primal-dual blossom algorithm
+    /// (Edmonds' algorithm with Galil's O(V^3) bookkeeping).
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`. Self-loops are ignored.
+    /// * `max_cardinality` - When `true`, only maximum-cardinality matchings are considered and the heaviest of them is returned.
+    ///
+    /// # Returns
+    ///
+    /// * `WeightedMatching` - The matched pairs and their total weight.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// g.add_edge(0, 1, 4);
+    /// g.add_edge(1, 2, 10);
+    /// g.add_edge(2, 3, 5);
+    ///
+    /// //The single heaviest edge beats the two lighter ones
+    /// assert_eq!(max_weight_matching(&g, false).total_weight, 10);
+    /// //Two pairs are required when the matching must be as large as possible
+    /// assert_eq!(max_weight_matching(&g, true).pairs, vec![(0, 1), (2, 3)]);
+    /// ```
+    pub fn max_weight_matching(g: &dijkstras::Graph, max_cardinality: bool) -> WeightedMatching {
+        let n = g.vertex_count();
+        let edges: Vec<(usize, usize, i64)> = g
+            .edge_list()
+            .into_iter()
+            .filter(|&(u, v, _)| u != v)
+            .map(|(u, v, w)| (u, v, w as i64))
+            .collect();
+        let max_weight = edges.iter().map(|&(_, _, w)| w).max().unwrap_or(0).max(0);
+        let mut neighbend = vec![Vec::new(); n];
+        for (k, &(i, j, _)) in edges.iter().enumerate() {
+            neighbend[i].push(2 * k + 1);
+            neighbend[j].push(2 * k);
+        }
+        let mut s = WeightedSearch {
+            n,
+            endpoint: (0..2 * edges.len())
+                .map(|p| if p % 2 == 0 { edges[p / 2].0 } else { edges[p / 2].1 })
+                .collect(),
+            neighbend,
+            mate: vec![NONE; n],
+            label: vec![0; 2 * n],
+            labelend: vec![NONE; 2 * n],
+            inblossom: (0..n).collect(),
+            blossomparent: vec![NONE; 2 * n],
+            blossomchilds: vec![Vec::new(); 2 * n],
+            blossombase: (0..2 * n).map(|b| if b < n { b } else { NONE }).collect(),
+            blossomendps: vec![Vec::new(); 2 * n],
+            bestedge: vec![NONE; 2 * n],
+            blossombestedges: vec![None; 2 * n],
+            unusedblossoms: (n..2 * n).collect(),
+            dualvar: (0..2 * n).map(|i| if i < n { max_weight } else { 0 }).collect(),
+            allowedge: vec![false; edges.len()],
+            queue: Vec::new(),
+            edges,
+        };
+
+        //Every stage either augments the matching by one edge or proves that no augmentation is left
+        for _ in 0..n {
+            s.label = vec![0; 2 * n];
+            s.bestedge = vec![NONE; 2 * n];
+            for b in n..2 * n {
+                s.blossombestedges[b] = None;
+            }
+            s.allowedge = vec![false; s.edges.len()];
+            s.queue.clear();
+            //Every free vertex is the root of an alternating tree
+            for v in 0..n {
+                if s.mate[v] == NONE && s.label[s.inblossom[v]] == 0 {
+                    s.assign_label(v, 1, NONE);
+                }
+            }
+
+            let mut augmented = false;
+            loop {
+                //Grow the alternating forest along tight edges
+                while !augmented {
+                    let v = match s.queue.pop() {
+                        Some(v) => v,
+                        None => break,
+                    };
+                    for idx in 0..s.neighbend[v].len() {
+                        let p = s.neighbend[v][idx];
+                        let k = p / 2;
+                        let w = s.endpoint[p];
+                        if s.inblossom[v] == s.inblossom[w] {
+                            continue; //Edge inside a blossom
+                        }
+                        let mut kslack = 0;
+                        if !s.allowedge[k] {
+                            kslack = s.slack(k);
+                            if kslack <= 0 {
+                                s.allowedge[k] = true;
+                            }
+                        }
+                        if s.allowedge[k] {
+                            if s.label[s.inblossom[w]] == 0 {
+                                s.assign_label(w, 2, p ^ 1); //w is free, label it T
+                            } else if s.label[s.inblossom[w]] == 1 {
+                                //Two S-vertices: either a new blossom or an augmenting path
+                                let base = s.scan_blossom(v, w);
+                                if base != NONE {
+                                    s.add_blossom(base, k);
+                                } else {
+                                    s.augment_matching(k);
+                                    augmented = true;
+                                    break;
+                                }
+                            } else if s.label[w] == 0 {
+                                //w is inside a T-blossom but was not reached yet
+                                s.label[w] = 2;
+                                s.labelend[w] = p ^ 1;
+                            }
+                        } else if s.label[s.inblossom[w]] == 1 {
+                            let b = s.inblossom[v];
+                            if s.bestedge[b] == NONE || kslack < s.slack(s.bestedge[b]) {
+                                s.bestedge[b] = k;
+                            }
+                        } else if s.label[w] == 0
+                            && (s.bestedge[w] == NONE || kslack < s.slack(s.bestedge[w]))
+                        {
+                            s.bestedge[w] = k;
+                        }
+                    }
+                }
+                if augmented {
+                    break;
+                }
+
+                //No tight edge is left, so change the dual variables by the largest safe amount
+                let mut deltatype = 0;
+                let mut delta = 0;
+                let mut deltaedge = NONE;
+                let mut deltablossom = NONE;
+                if !max_cardinality {
+                    deltatype = 1;
+                    delta = s.dualvar[..n].iter().copied().min().unwrap_or(0);
+                }
+                for v in 0..n {
+                    if s.label[s.inblossom[v]] == 0 && s.bestedge[v] != NONE {
+                        let d = s.slack(s.bestedge[v]);
+                        if deltatype == 0 || d < delta {
+                            delta = d;
+                            deltatype = 2;
+                            deltaedge = s.bestedge[v];
+                        }
+                    }
+                }
+                for b in 0..2 * n {
+                    if s.blossomparent[b] == NONE && s.label[b] == 1 && s.bestedge[b] != NONE {
+                        let d = s.slack(s.bestedge[b]) / 2;
+                        if deltatype == 0 || d < delta {
+                            delta = d;
+                            deltatype = 3;
+                            deltaedge = s.bestedge[b];
+                        }
+                    }
+                }
+                for b in n..2 * n {
+                    if s.blossombase[b] != NONE
+                        && s.blossomparent[b] == NONE
+                        && s.label[b] == 2
+                        && (deltatype == 0 || s.dualvar[b] < delta)
+                    {
+                        delta = s.dualvar[b];
+                        deltatype = 4;
+                        deltablossom = b;
+                    }
+                }
+                if deltatype == 0 {
+                    //No further improvement is possible in maximum-cardinality mode, finish with the vertex duals
+                    deltatype = 1;
+                    delta = s.dualvar[..n].iter().copied().min().unwrap_or(0).max(0);
+                }
+
+                for v in 0..n {
+                    match s.label[s.inblossom[v]] {
+                        1 => s.dualvar[v] -= delta,
+                        2 => s.dualvar[v] += delta,
+                        _ => {}
+                    }
+                }
+                for b in n..2 * n {
+                    if s.blossombase[b] != NONE && s.blossomparent[b] == NONE {
+                        match s.label[b] {
+                            1 => s.dualvar[b] += delta,
+                            2 => s.dualvar[b] -= delta,
+                            _ => {}
+                        }
+                    }
+                }
+
+                match deltatype {
+                    1 => break, //Optimum reached
+                    2 => {
+                        s.allowedge[deltaedge] = true;
+                        let (mut i, j, _) = s.edges[deltaedge];
+                        if s.label[s.inblossom[i]] == 0 {
+                            i = j;
+                        }
+                        s.queue.push(i);
+                    }
+                    3 => {
+                        s.allowedge[deltaedge] = true;
+                        let (i, _, _) = s.edges[deltaedge];
+                        s.queue.push(i);
+                    }
+                    _ => s.expand_blossom(deltablossom, false),
+                }
+            }
+
+            if !augmented {
+                break;
+            }
+            //Expand the S-blossoms whose dual variable dropped to zero
+            for b in n..2 * n {
+                if s.blossomparent[b] == NONE
+                    && s.blossombase[b] != NONE
+                    && s.label[b] == 1
+                    && s.dualvar[b] == 0
+                {
+                    s.expand_blossom(b, true);
+                }
+            }
+        }
+
+        let mut pairs = Vec::new();
+        let mut total_weight = 0;
+        for v in 0..n {
+            if s.mate[v] != NONE {
+                let u = s.endpoint[s.mate[v]];
+                if v < u {
+                    pairs.push((v, u));
+                    total_weight += s.edges[s.mate[v] / 2].2 as i32;
+                }
+            }
+        }
+        WeightedMatching {
+            pairs,
+            total_weight,
+        }
+    }
+
+    /// Finds maximum matchings of a weighted undirected graph entered by the user.
+    ///
+    /// # Inputs
+    ///
+    /// * `vertices` - Total number of vertices in the graph.
+    ///
+    /// * `edges` - Total Number of edges in the graph.
+    ///
+    /// * `Source(s) Destination(d) Weight(w) ` - Source, Destination and Weight for each edge in the graph.
+    ///
+    /// # Output
+    ///
+    /// Prints a maximum-cardinality matching, a maximum-weight matching and the heaviest maximum-cardinality matching.
+    ///
+    /// # Sample Input
+    /// ```
+    /// Please Enter Number of Vertices in the Graph : 4
+    /// Please Enter Number of edges in the graph : 3
+    /// Please Enter Edge 1 values
+    /// Source : 0
+    /// Destination : 1
+    /// Weight(>0) : 4
+    /// Please Enter Edge 2 values
+    /// Source : 1
+    /// Destination : 2
+    /// Weight(>0) : 10
+    /// Please Enter Edge 3 values
+    /// Source : 2
+    /// Destination : 3
+    /// Weight(>0) : 5
+    /// ```
+    /// # Sample Output
+    /// ```
+    /// Maximum-cardinality matching : [(0, 1), (2, 3)]
+    /// Maximum-weight matching : [(1, 2)] with weight 10
+    /// Heaviest maximum-cardinality matching : [(0, 1), (2, 3)] with weight 9
+    /// ```
+    pub fn general_matching() {
+        // Create an empty string to store the number of vertices
+        let mut ve = String::new();
+
+        // Printing the introduction message
+        println!("******Blossom Matching*******");
+        println!("******************");
+
+        // Prompting user to input number of vertices in the Graph
+        print!("Please Enter Number of Vertices in the Graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut ve)
+            .expect("Please Enter Valid number for vertices.");
+        let vertices: usize = ve
+            .trim()
+            .parse()
+            .expect("Invalid input for number of vertices");
+
+        // Prompting user to input the number of edges
+        let mut edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut edges)
+            .expect("Please Enter Valid Input for number of Edges.");
+        let edges: i32 = edges
+            .trim()
+            .parse()
+            .expect("Invalid input for number of edges");
+
+        // Reading all edges source, destination and weight from the user
+        let g = add_edges(vertices, edges);
+        // The unweighted algorithm works on the same edges without their weights
+        let mut unweighted = bfs::Graph::new(vertices);
+        for (u, v, _) in g.edge_list() {
+            unweighted.add_edge(u, v);
+        }
+
+        println!("******************");
+        println!(
+            "Maximum-cardinality matching : {:?}",
+            maximum_matching(&unweighted)
+        );
+        let heaviest = max_weight_matching(&g, false);
+        println!(
+            "Maximum-weight matching : {:?} with weight {}",
+            heaviest.pairs, heaviest.total_weight
+        );
+        let largest = max_weight_matching(&g, true);
+        println!(
+            "Heaviest maximum-cardinality matching : {:?} with weight {}",
+            largest.pairs, largest.total_weight
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::blossom::*;
+    use crate::list_of_algorithms::bfs::bfs;
+    use crate::list_of_algorithms::dijkstras::dijkstras;
+
+    #[test]
+    fn test_odd_cycle_matching() {
+        //Two triangles joined by an edge need a blossom to find the perfect matching
+        let mut g = bfs::Graph::new(6);
+        for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)] {
+            g.add_edge(u, v);
+        }
+        let pairs = maximum_matching(&g);
+        assert_eq!(pairs.len(), 3);
+        let mut covered: Vec<usize> = pairs.iter().flat_map(|&(u, v)| [u, v]).collect();
+        covered.sort();
+        assert_eq!(covered, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_petersen_graph() {
+        //The Petersen graph is not bipartite and has a perfect matching
+        let mut g = bfs::Graph::new(10);
+        for i in 0..5 {
+            g.add_edge(i, (i + 1) % 5);
+            g.add_edge(i, i + 5);
+            g.add_edge(i + 5, (i + 2) % 5 + 5);
+        }
+        assert_eq!(maximum_matching(&g).len(), 5);
+
+        let mut g = bfs::Graph::new(5);
+        for i in 0..5 {
+            g.add_edge(i, (i + 1) % 5); //A 5-cycle leaves one vertex unmatched
+        }
+        assert_eq!(maximum_matching(&g).len(), 2);
+    }
+
+    #[test]
+    fn test_max_weight_matching() {
+        let mut g = dijkstras::Graph::new(4);
+        g.add_edge(0, 1, 4);
+        g.add_edge(1, 2, 10);
+        g.add_edge(2, 3, 5);
+
+        let heaviest = max_weight_matching(&g, false);
+        assert_eq!(heaviest.pairs, vec![(1, 2)]);
+        assert_eq!(heaviest.total_weight, 10);
+        let largest = max_weight_matching(&g, true);
+        assert_eq!(largest.pairs, vec![(0, 1), (2, 3)]);
+        assert_eq!(largest.total_weight, 9);
+    }
+
+    #[test]
+    fn test_max_weight_matching_with_blossom() {
+        //The heaviest edge 1-2 lies in a triangle blossom but is not part of the optimum
+        let mut g = dijkstras::Graph::new(6);
+        g.add_edge(0, 1, 9);
+        g.add_edge(0, 2, 9);
+        g.add_edge(1, 2, 10);
+        g.add_edge(1, 3, 8);
+        g.add_edge(2, 4, 8);
+        g.add_edge(4, 5, 1);
+        g.add_edge(0, 5, 1);
+
+        let result = max_weight_matching(&g, false);
+        assert_eq!(result.total_weight, 18);
+        assert_eq!(result.pairs, vec![(0, 2), (1, 3), (4, 5)]);
+    }
+}
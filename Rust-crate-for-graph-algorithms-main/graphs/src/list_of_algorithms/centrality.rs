@@ -0,0 +1,434 @@
+pub mod centrality {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::bfs::bfs;
+    use crate::list_of_algorithms::dijkstras::dijkstras::{self, add_edges};
+    use crate::list_of_algorithms::random::random::XorShift;
+    use std::io::{stdin, stdout, Write};
+
+    /// The source vertices whose shortest paths are counted by `betweenness` and `weighted_betweenness`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum Sources {
+        /// Every vertex, giving exact values.
+        All,
+        /// `count` distinct vertices drawn at random from a generator seeded with `seed`.
+        /// The result is scaled by `V / count`, an unbiased estimate of the exact values.
+        Sample { count: usize, seed: u64 },
+    }
+
+    //Shortest paths from one source: vertices by non-decreasing distance, number of shortest paths and predecessors
+    struct ShortestPaths {
+        order: Vec<usize>,
+        dist: Vec<i64>,
+        sigma: Vec<f64>,
+        preds: Vec<Vec<usize>>,
+    }
+
+    //Shortest paths counting edges, from the BFS tree of bfs::Graph
+    fn hop_paths(g: &bfs::Graph, s: usize) -> ShortestPaths {
+        let n = g.edges.len();
+        let mut parent = vec![usize::MAX; n];
+        let mut depth = vec![usize::MAX; n];
+        let order = g.bfs_tree(s, &mut parent, &mut depth);
+        let mut sigma = vec![0.0; n];
+        let mut preds = vec![Vec::new(); n];
+        sigma[s] = 1.0;
+        for &u in &order {
+            for &v in &g.edges[u] {
+                if depth[v] == depth[u] + 1 {
+                    sigma[v] += sigma[u];
+                    preds[v].push(u);
+                }
+            }
+        }
+        let dist = depth
+            .into_iter()
+            .map(|d| if d == usize::MAX { -1 } else { d as i64 })
+            .collect();
+        ShortestPaths {
+            order,
+            dist,
+            sigma,
+            preds,
+        }
+    }
+
+    //Weighted shortest paths from the distances computed by dijkstra
+    fn weighted_paths(g: &dijkstras::Graph, s: usize) -> ShortestPaths {
+        let n = g.vertex_count();
+        let dist = g.dijkstra(s);
+        let mut order: Vec<usize> = (0..n).filter(|&u| dist[u] != i32::MAX).collect();
+        order.sort_by_key(|&u| dist[u]);
+        let mut sigma = vec![0.0; n];
+        let mut preds = vec![Vec::new(); n];
+        sigma[s] = 1.0;
+        for &u in &order {
+            for (v, w) in g.neighbors(u) {
+                //Edges that keep the distance tight lie on shortest paths
+                if v != u && dist[u] + w == dist[v] {
+                    sigma[v] += sigma[u];
+                    preds[v].push(u);
+                }
+            }
+        }
+        ShortestPaths {
+            order,
+            dist: dist
+                .into_iter()
+                .map(|d| if d == i32::MAX { -1 } else { d as i64 })
+                .collect(),
+            sigma,
+            preds,
+        }
+    }
+
+    //Brandes' dependency accumulation over the chosen sources
+    fn brandes(n: usize, sources: Sources, paths: impl Fn(usize) -> ShortestPaths) -> Vec<f64> {
+        let (chosen, scale): (Vec<usize>, f64) = match sources {
+            Sources::Sample { count, seed } if count < n => {
+                //Partial Fisher-Yates shuffle to draw count distinct sources
+                let mut rng = XorShift::new(seed);
+                let mut all: Vec<usize> = (0..n).collect();
+                for i in 0..count {
+                    let j = i + rng.below(n - i);
+                    all.swap(i, j);
+                }
+                all.truncate(count);
+                (all, n as f64 / count.max(1) as f64)
+            }
+            _ => ((0..n).collect(), 1.0),
+        };
+
+        let mut centrality = vec![0.0; n];
+        for s in chosen {
+            let sp = paths(s);
+            let mut delta = vec![0.0; n];
+            //Vertices farthest from s pass their dependency back to their predecessors first
+            for &w in sp.order.iter().rev() {
+                for &v in &sp.preds[w] {
+                    delta[v] += sp.sigma[v] / sp.sigma[w] * (1.0 + delta[w]);
+                }
+                if w != s {
+                    centrality[w] += delta[w];
+                }
+            }
+        }
+        //Every pair of an undirected graph was counted from both ends
+        centrality.iter().map(|c| c * scale / 2.0).collect()
+    }
+
+    //Closeness scaled by the reachable fraction (Wasserman and Faust) from single-source distances
+    fn closeness_from(n: usize, paths: impl Fn(usize) -> ShortestPaths) -> Vec<f64> {
+        (0..n)
+            .map(|u| {
+                let dist = paths(u).dist;
+                let reachable: Vec<i64> = dist.into_iter().filter(|&d| d > 0).collect();
+                let total: i64 = reachable.iter().sum();
+                if total == 0 || n < 2 {
+                    return 0.0;
+                }
+                let r = reachable.len() as f64;
+                (r / total as f64) * (r / (n - 1) as f64)
+            })
+            .collect()
+    }
+
+    //Sum of the reciprocal distances to all other reachable vertices
+    fn harmonic_from(n: usize, paths: impl Fn(usize) -> ShortestPaths) -> Vec<f64> {
+        (0..n)
+            .map(|u| {
+                paths(u)
+                    .dist
+                    .into_iter()
+                    .filter(|&d| d > 0)
+                    .map(|d| 1.0 / d as f64)
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Brandes' algorithm computes the betweenness centrality of every vertex of an unweighted graph in O(V * E) time:
+    /// the number of shortest paths between other pairs of vertices that pass through it, where a pair with several
+    /// shortest paths contributes the fraction of them through the vertex. Shortest paths from every source come from
+    /// a Breadth-First Search, and dependencies are accumulated from the farthest vertices back to the source.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Parallel edges count as separate paths.
+    /// * `sources` - Either all vertices or a random sample of them as sources.
+    ///
+    /// # Returns
+    ///
+    /// * `centrality` - The betweenness of every vertex, with every unordered pair counted once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //In the path 0-1-2-3, vertex 1 lies on the paths 0-2 and 0-3
+    /// let mut g = Graph::new(4);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(1, 2);
+    /// g.add_edge(2, 3);
+    ///
+    /// assert_eq!(betweenness(&g, Sources::All), vec![0.0, 2.0, 2.0, 0.0]);
+    /// ```
+    pub fn betweenness(g: &bfs::Graph, sources: Sources) -> Vec<f64> {
+        brandes(g.edges.len(), sources, |s| hop_paths(g, s))
+    }
+
+    /// Brandes' betweenness centrality of a weighted graph, with shortest paths from `dijkstra`. See `betweenness`.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`, with positive weights.
+    /// * `sources` - Either all vertices or a random sample of them as sources.
+    ///
+    /// # Returns
+    ///
+    /// * `centrality` - The betweenness of every vertex, with every unordered pair counted once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //The heavy edge 0-2 is avoided, so vertex 1 lies on the shortest path from 0 to 2
+    /// let mut g = Graph::new(3);
+    /// g.add_edge(0, 1, 1);
+    /// g.add_edge(1, 2, 1);
+    /// g.add_edge(0, 2, 5);
+    ///
+    /// assert_eq!(weighted_betweenness(&g, Sources::All), vec![0.0, 1.0, 0.0]);
+    /// ```
+    pub fn weighted_betweenness(g: &dijkstras::Graph, sources: Sources) -> Vec<f64> {
+        brandes(g.vertex_count(), sources, |s| weighted_paths(g, s))
+    }
+
+    /// Computes the closeness centrality of every vertex of an unweighted graph: the number of other vertices it reaches
+    /// divided by the sum of the distances to them, scaled by the fraction of the graph it reaches so that vertices of
+    /// small components do not get a high closeness (the Wasserman and Faust variant).
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`.
+    ///
+    /// # Returns
+    ///
+    /// * `centrality` - The closeness of every vertex, 0 for isolated vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(3);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(1, 2);
+    ///
+    /// assert_eq!(closeness(&g), vec![2.0 / 3.0, 1.0, 2.0 / 3.0]);
+    /// ```
+    pub fn closeness(g: &bfs::Graph) -> Vec<f64> {
+        closeness_from(g.edges.len(), |u| hop_paths(g, u))
+    }
+
+    /// Computes the closeness centrality of every vertex of a weighted graph with `dijkstra`. See `closeness`.
+    pub fn weighted_closeness(g: &dijkstras::Graph) -> Vec<f64> {
+        closeness_from(g.vertex_count(), |u| weighted_paths(g, u))
+    }
+
+    /// Computes the harmonic centrality of every vertex of an unweighted graph: the sum of the reciprocal distances
+    /// to all other vertices, where unreachable vertices contribute 0. Unlike closeness it needs no correction for
+    /// disconnected graphs.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`.
+    ///
+    /// # Returns
+    ///
+    /// * `centrality` - The harmonic centrality of every vertex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(1, 2);
+    ///
+    /// assert_eq!(harmonic(&g), vec![1.5, 2.0, 1.5, 0.0]);
+    /// ```
+    pub fn harmonic(g: &bfs::Graph) -> Vec<f64> {
+        harmonic_from(g.edges.len(), |u| hop_paths(g, u))
+    }
+
+    /// Computes the harmonic centrality of every vertex of a weighted graph with `dijkstra`. See `harmonic`.
+    pub fn weighted_harmonic(g: &dijkstras::Graph) -> Vec<f64> {
+        harmonic_from(g.vertex_count(), |u| weighted_paths(g, u))
+    }
+
+    /// Computes centrality measures of a weighted undirected graph entered by the user,
+    /// both with the edge weights and counting edges.
+    ///
+    /// # Inputs
+    ///
+    /// * `vertices` - Total number of vertices in the graph.
+    ///
+    /// * `edges` - Total Number of edges in the graph.
+    ///
+    /// * `Source(s) Destination(d) Weight(w) ` - Source, Destination and Weight for each edge in the graph.
+    ///
+    /// * `samples` - The number of sampled sources for betweenness, 0 for exact values.
+    ///
+    /// # Output
+    ///
+    /// Prints the betweenness, closeness and harmonic centrality of every vertex.
+    ///
+    /// # Sample Input
+    /// ```
+    /// Please Enter Number of Vertices in the Graph : 3
+    /// Please Enter Number of edges in the graph : 3
+    /// Please Enter Edge 1 values
+    /// Source : 0
+    /// Destination : 1
+    /// Weight(>0) : 1
+    /// Please Enter Edge 2 values
+    /// Source : 1
+    /// Destination : 2
+    /// Weight(>0) : 1
+    /// Please Enter Edge 3 values
+    /// Source : 0
+    /// Destination : 2
+    /// Weight(>0) : 5
+    /// Please Enter Number of Sampled Sources (0 for exact) : 0
+    /// ```
+    /// # Sample Output
+    /// ```
+    /// Weighted betweenness : [0.000, 1.000, 0.000]
+    /// Weighted closeness : [0.667, 1.000, 0.667]
+    /// Weighted harmonic : [1.500, 2.000, 1.500]
+    /// Betweenness : [0.000, 0.000, 0.000]
+    /// Closeness : [1.000, 1.000, 1.000]
+    /// Harmonic : [2.000, 2.000, 2.000]
+    /// ```
+    pub fn centrality() {
+        // Create an empty string to store the number of vertices
+        let mut ve = String::new();
+
+        // Printing the introduction message
+        println!("******Centrality*******");
+        println!("******************");
+
+        // Prompting user to input number of vertices in the Graph
+        print!("Please Enter Number of Vertices in the Graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut ve)
+            .expect("Please Enter Valid number for vertices.");
+        let vertices: usize = ve
+            .trim()
+            .parse()
+            .expect("Invalid input for number of vertices");
+
+        // Prompting user to input the number of edges
+        let mut edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut edges)
+            .expect("Please Enter Valid Input for number of Edges.");
+        let edges: i32 = edges
+            .trim()
+            .parse()
+            .expect("Invalid input for number of edges");
+
+        // Reading all edges source, destination and weight from the user
+        let g = add_edges(vertices, edges);
+        // The unweighted measures count the edges of the same graph
+        let mut unweighted = bfs::Graph::new(vertices);
+        for (u, v, _) in g.edge_list() {
+            unweighted.add_edge(u, v);
+        }
+
+        // Prompting user to input the number of sampled sources
+        let mut samples = String::new();
+        print!("Please Enter Number of Sampled Sources (0 for exact) : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut samples)
+            .expect("Please Enter Valid Input for number of samples.");
+        let samples: usize = samples.trim().parse().expect("Invalid input for samples");
+        let sources = if samples == 0 {
+            Sources::All
+        } else {
+            Sources::Sample {
+                count: samples,
+                seed: 1,
+            }
+        };
+
+        println!("******************");
+        println!(
+            "Weighted betweenness : {:.3?}",
+            weighted_betweenness(&g, sources)
+        );
+        println!("Weighted closeness : {:.3?}", weighted_closeness(&g));
+        println!("Weighted harmonic : {:.3?}", weighted_harmonic(&g));
+        println!("Betweenness : {:.3?}", betweenness(&unweighted, sources));
+        println!("Closeness : {:.3?}", closeness(&unweighted));
+        println!("Harmonic : {:.3?}", harmonic(&unweighted));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::centrality::*;
+    use crate::list_of_algorithms::bfs::bfs;
+    use crate::list_of_algorithms::dijkstras::dijkstras;
+
+    #[test]
+    fn test_betweenness() {
+        //A star: the center lies on the shortest path of every pair of leaves
+        let mut g = bfs::Graph::new(5);
+        for leaf in 1..5 {
+            g.add_edge(0, leaf);
+        }
+        assert_eq!(betweenness(&g, Sources::All), vec![6.0, 0.0, 0.0, 0.0, 0.0]);
+
+        //In a 4-cycle every opposite pair has two shortest paths, each middle vertex gets half
+        let mut g = bfs::Graph::new(4);
+        for u in 0..4 {
+            g.add_edge(u, (u + 1) % 4);
+        }
+        assert_eq!(betweenness(&g, Sources::All), vec![0.5; 4]);
+    }
+
+    #[test]
+    fn test_sampled_betweenness() {
+        let mut g = bfs::Graph::new(6);
+        for u in 0..5 {
+            g.add_edge(u, u + 1);
+        }
+        let exact = betweenness(&g, Sources::All);
+        //Sampling every vertex gives the exact values, and the same seed gives the same estimate
+        let all = Sources::Sample { count: 6, seed: 7 };
+        assert_eq!(betweenness(&g, all), exact);
+        let some = Sources::Sample { count: 3, seed: 7 };
+        assert_eq!(betweenness(&g, some), betweenness(&g, some));
+        assert!(betweenness(&g, some).iter().all(|&c| c >= 0.0));
+    }
+
+    #[test]
+    fn test_closeness_and_harmonic() {
+        let mut g = dijkstras::Graph::new(4);
+        g.add_edge(0, 1, 2);
+        g.add_edge(1, 2, 2);
+        //Vertex 0 reaches 2 of the 3 other vertices at total distance 6
+        let expected = [2.0 / 9.0, 1.0 / 3.0, 2.0 / 9.0, 0.0];
+        for (c, e) in weighted_closeness(&g).iter().zip(expected) {
+            assert!((c - e).abs() < 1e-12);
+        }
+        assert_eq!(weighted_harmonic(&g), vec![0.75, 1.0, 0.75, 0.0]);
+
+        let mut g = bfs::Graph::new(3);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 0);
+        assert_eq!(closeness(&g), vec![1.0; 3]);
+        assert_eq!(harmonic(&g), vec![2.0; 3]);
+    }
+}
@@ -0,0 +1,304 @@
+pub mod cliques {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::bfs::bfs::{add_edges, Graph};
+    use crate::list_of_algorithms::clustering::clustering::{core_decomposition, simple_neighbors};
+    use std::io::{stdin, stdout, Write};
+    use std::time::{Duration, Instant};
+
+    /// The largest clique found by `maximum_clique`.
+    #[derive(Debug, PartialEq)]
+    pub struct MaximumClique {
+        /// The vertices of the clique in increasing order.
+        pub vertices: Vec<usize>,
+        /// Whether the search finished, so that no larger clique exists. `false` when the timeout stopped it first.
+        pub optimal: bool,
+    }
+
+    //Keeps the vertices of a set that are in the sorted neighbors of a vertex, in their order
+    fn intersect(set: &[usize], neighbors: &[usize]) -> Vec<usize> {
+        set.iter().copied().filter(|v| neighbors.binary_search(v).is_ok()).collect()
+    }
+
+    //The Tomita pivot: the vertex of P or X with the most neighbors in P, whose neighbors need not be branched on
+    fn pivot(adj: &[Vec<usize>], p: &[usize], x: &[usize]) -> usize {
+        p.iter()
+            .chain(x)
+            .copied()
+            .max_by_key(|&u| intersect(p, &adj[u]).len())
+            .expect("The pivot is only chosen while P is not empty")
+    }
+
+    //Bron-Kerbosch with pivoting: r is the current clique, p the vertices that may extend it and x those already tried
+    fn bron_kerbosch(
+        adj: &[Vec<usize>],
+        r: &mut Vec<usize>,
+        mut p: Vec<usize>,
+        mut x: Vec<usize>,
+        found: &mut Vec<Vec<usize>>,
+    ) {
+        if p.is_empty() {
+            if x.is_empty() {
+                let mut clique = r.clone();
+                clique.sort_unstable();
+                found.push(clique);
+            }
+            return;
+        }
+        let u = pivot(adj, &p, &x);
+        let candidates: Vec<usize> = p.iter().copied().filter(|v| adj[u].binary_search(v).is_err()).collect();
+        for v in candidates {
+            r.push(v);
+            bron_kerbosch(adj, r, intersect(&p, &adj[v]), intersect(&x, &adj[v]), found);
+            r.pop();
+            p.retain(|&w| w != v);
+            x.push(v);
+        }
+    }
+
+    /// Enumerates the maximal cliques of an undirected graph with the Bron–Kerbosch algorithm, using Tomita pivoting
+    /// inside and the degeneracy ordering of Eppstein, Löffler and Strash outside: each vertex is the first of its cliques
+    /// in that ordering, so only its later neighbors can extend it. Runs in O(d V 3^(d/3)) time for degeneracy d.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Self-loops and parallel edges are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `cliques` - Every maximal clique once, each in increasing order, sorted.
+    ///   An isolated vertex is a maximal clique of its own.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //A triangle with a pendant vertex 3
+    /// let mut g = Graph::new(4);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+    ///     g.add_edge(u, v);
+    /// }
+    /// assert_eq!(maximal_cliques(&g), vec![vec![0, 1, 2], vec![2, 3]]);
+    /// ```
+    pub fn maximal_cliques(g: &Graph) -> Vec<Vec<usize>> {
+        let adj = simple_neighbors(g);
+        let order = core_decomposition(g).order;
+        let mut position = vec![0; adj.len()];
+        for (i, &v) in order.iter().enumerate() {
+            position[v] = i;
+        }
+
+        let mut found = Vec::new();
+        for &v in &order {
+            let p = adj[v].iter().copied().filter(|&u| position[u] > position[v]).collect();
+            let x = adj[v].iter().copied().filter(|&u| position[u] < position[v]).collect();
+            bron_kerbosch(&adj, &mut vec![v], p, x, &mut found);
+        }
+        found.sort();
+        found
+    }
+
+    //State of the branch and bound search for a maximum clique
+    struct Search<'a> {
+        adj: &'a [Vec<usize>],
+        best: Vec<usize>,
+        deadline: Option<Instant>,
+        timed_out: bool,
+    }
+
+    impl Search<'_> {
+        fn expand(&mut self, r: &mut Vec<usize>, mut p: Vec<usize>) {
+            if self.deadline.is_some_and(|d| Instant::now() >= d) {
+                self.timed_out = true;
+                return;
+            }
+            if p.is_empty() {
+                if r.len() > self.best.len() {
+                    self.best = r.clone();
+                }
+                return;
+            }
+            //Every vertex of the clique outside r is in p, so a branch no larger than the best so far is cut
+            while let Some(v) = p.pop() {
+                if r.len() + 1 + p.len() <= self.best.len() || self.timed_out {
+                    return;
+                }
+                r.push(v);
+                let next = intersect(&p, &self.adj[v]);
+                self.expand(r, next);
+                r.pop();
+            }
+        }
+    }
+
+    /// Finds a largest clique by branch and bound, extending cliques one vertex at a time and cutting every branch
+    /// that cannot beat the largest clique found so far. Candidates are tried in degeneracy order, so that
+    /// dense parts of the graph are searched first.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Self-loops and parallel edges are ignored.
+    /// * `timeout` - When given, the search stops after this long and returns the largest clique found so far.
+    ///
+    /// # Returns
+    ///
+    /// * `MaximumClique` - The clique, and whether it is known to be a largest one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+    ///     g.add_edge(u, v);
+    /// }
+    /// let clique = maximum_clique(&g, None);
+    /// assert_eq!(clique.vertices, vec![0, 1, 2]);
+    /// assert!(clique.optimal);
+    /// ```
+    pub fn maximum_clique(g: &Graph, timeout: Option<Duration>) -> MaximumClique {
+        let adj = simple_neighbors(g);
+        let mut search = Search {
+            adj: &adj,
+            best: Vec::new(),
+            deadline: timeout.map(|t| Instant::now() + t),
+            timed_out: false,
+        };
+        //Candidates are popped from the back and intersections keep their order,
+        //so the vertices peeled off last, in the densest cores, are tried first
+        let order = core_decomposition(g).order;
+        search.expand(&mut Vec::new(), order);
+
+        let mut vertices = search.best;
+        vertices.sort_unstable();
+        MaximumClique {
+            vertices,
+            optimal: !search.timed_out,
+        }
+    }
+
+    /// Lists the maximal cliques and finds a maximum clique of a graph entered by the user.
+    ///
+    /// # Input
+    /// * `no_of_vertices` - Input the number of vertices in the graph
+    /// * `no_of_edges` - Input the number of edges in the graph
+    /// * `source` - The source vertex of an edge in the graph
+    /// * `destination` - The destination of an edge in the graph
+    /// * `timeout` - The time limit of the maximum clique search in milliseconds, 0 for no limit
+    ///
+    /// # Output
+    ///
+    /// Prints every maximal clique and a maximum clique
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 4
+    ///Please Enter Number of edges in the graph : 4
+    ///Source : 0
+    ///Destination : 1
+    ///Source : 1
+    ///Destination : 2
+    ///Source : 2
+    ///Destination : 0
+    ///Source : 2
+    ///Destination : 3
+    ///Enter Time Limit in Milliseconds (0 for no limit) : 0
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///Maximal clique 1 : [0, 1, 2]
+    ///Maximal clique 2 : [2, 3]
+    ///Maximum clique : [0, 1, 2]
+    /// ```
+    pub fn cliques() {
+        //read the number of vertices from the console
+        let mut vertex = String::new();
+        println!("*****Cliques********");
+        println!("****************************************************");
+        print!("Please Enter Number of Vertices : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut vertex)
+            .expect("Enter valid number of vertices");
+        let vertices: usize = vertex.trim().parse().expect("Invalid input");
+        //get number of edges in the graph
+        let mut n_edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut n_edges).expect("Enter Valid Input");
+        let n_edges: i32 = n_edges.trim().parse().expect("Invalid input for edges");
+        //assign the vertices to each edge from the console
+        let g = add_edges(vertices, n_edges);
+        //get the time limit, where 0 means no limit
+        let mut millis = String::new();
+        print!("Enter Time Limit in Milliseconds (0 for no limit) : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut millis).expect("Enter Valid Input");
+        let millis: u64 = millis.trim().parse().expect("Invalid input for time limit");
+        let timeout = if millis == 0 { None } else { Some(Duration::from_millis(millis)) };
+
+        println!("******************");
+        for (i, clique) in maximal_cliques(&g).iter().enumerate() {
+            println!("Maximal clique {} : {:?}", i + 1, clique);
+        }
+        let maximum = maximum_clique(&g, timeout);
+        if maximum.optimal {
+            println!("Maximum clique : {:?}", maximum.vertices);
+        } else {
+            println!("Largest clique found before the time limit : {:?}", maximum.vertices);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cliques::*;
+    use crate::list_of_algorithms::bfs::bfs::Graph;
+    use std::time::Duration;
+
+    #[test]
+    fn test_maximal_cliques() {
+        //The octahedron K(2, 2, 2) has 8 maximal cliques, its triangular faces
+        let mut g = Graph::new(6);
+        for u in 0..6 {
+            for v in u + 1..6 {
+                if v != u + 3 {
+                    g.add_edge(u, v);
+                }
+            }
+        }
+        let cliques = maximal_cliques(&g);
+        assert_eq!(cliques.len(), 8);
+        assert!(cliques.iter().all(|c| c.len() == 3));
+        assert_eq!(cliques[0], vec![0, 1, 2]);
+
+        //Isolated vertices, self-loops and parallel edges
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1);
+        g.add_edge(0, 1);
+        g.add_edge(2, 2);
+        assert_eq!(maximal_cliques(&g), vec![vec![0, 1], vec![2]]);
+        assert!(maximal_cliques(&Graph::new(0)).is_empty());
+    }
+
+    #[test]
+    fn test_maximum_clique() {
+        //A 5-cycle, a K4 on {4, 5, 6, 7} sharing vertex 4 with it, and vertex 8 hanging off 7
+        let mut g = Graph::new(9);
+        for u in 0..5 {
+            g.add_edge(u, (u + 1) % 5);
+        }
+        for u in [4, 5, 6, 7] {
+            for v in [4, 5, 6, 7] {
+                if u < v {
+                    g.add_edge(u, v);
+                }
+            }
+        }
+        g.add_edge(7, 8);
+        let clique = maximum_clique(&g, None);
+        assert_eq!(clique.vertices, vec![4, 5, 6, 7]);
+        assert!(clique.optimal);
+        assert!(maximum_clique(&Graph::new(0), None).vertices.is_empty());
+
+        //A zero time limit stops at once
+        assert!(!maximum_clique(&g, Some(Duration::ZERO)).optimal);
+    }
+}
@@ -0,0 +1,304 @@
+pub mod closure {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::kosaraju::kosaraju::{add_neighbors, kosaraju_algorithm};
+    use std::collections::VecDeque;
+
+    /// A square matrix of bits, one row of 64-bit words per vertex.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct BitMatrix {
+        words: usize, //words per row
+        bits: Vec<u64>,
+    }
+
+    impl BitMatrix {
+        fn new(n: usize) -> Self {
+            let words = n.div_ceil(64);
+            BitMatrix {
+                words,
+                bits: vec![0; n * words],
+            }
+        }
+
+        fn row_words(&self, u: usize) -> &[u64] {
+            &self.bits[u * self.words..(u + 1) * self.words]
+        }
+
+        /// Returns whether the bit in row `u` and column `v` is set.
+        pub fn contains(&self, u: usize, v: usize) -> bool {
+            self.bits[u * self.words + v / 64] >> (v % 64) & 1 == 1
+        }
+
+        /// Returns the columns of the set bits of row `u` in increasing order.
+        pub fn row(&self, u: usize) -> Vec<usize> {
+            let mut columns = Vec::new();
+            for (i, &word) in self.row_words(u).iter().enumerate() {
+                let mut word = word;
+                while word != 0 {
+                    columns.push(64 * i + word.trailing_zeros() as usize);
+                    word &= word - 1;
+                }
+            }
+            columns
+        }
+    }
+
+    //The strongly connected components of a graph, condensed into a DAG
+    struct Condensation {
+        members: Vec<Vec<usize>>,
+        component: Vec<usize>,
+        successors: Vec<Vec<usize>>, //sorted and without duplicates or self-loops
+        cyclic: Vec<bool>,           //whether the component contains a cycle, possibly a self-loop
+        reach: BitMatrix,            //the components reachable by at least one edge of the DAG
+    }
+
+    fn condense(adj_list: &[Vec<usize>]) -> Condensation {
+        let members = kosaraju_algorithm(&adj_list.to_vec());
+        let c = members.len();
+        let mut component = vec![0; adj_list.len()];
+        for (i, scc) in members.iter().enumerate() {
+            for &v in scc {
+                component[v] = i;
+            }
+        }
+        let mut successors = vec![Vec::new(); c];
+        let mut cyclic: Vec<bool> = members.iter().map(|scc| scc.len() > 1).collect();
+        for (u, adj) in adj_list.iter().enumerate() {
+            for &v in adj {
+                if component[u] == component[v] {
+                    cyclic[component[u]] = true;
+                } else {
+                    successors[component[u]].push(component[v]);
+                }
+            }
+        }
+        for list in successors.iter_mut() {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        //Topological order of the condensation by Kahn's algorithm, then reachability from the sinks upwards
+        let mut indegree = vec![0; c];
+        for list in &successors {
+            for &d in list {
+                indegree[d] += 1;
+            }
+        }
+        let mut queue: VecDeque<usize> = (0..c).filter(|&d| indegree[d] == 0).collect();
+        let mut order = Vec::with_capacity(c);
+        while let Some(a) = queue.pop_front() {
+            order.push(a);
+            for &d in &successors[a] {
+                indegree[d] -= 1;
+                if indegree[d] == 0 {
+                    queue.push_back(d);
+                }
+            }
+        }
+        let mut reach = BitMatrix::new(c);
+        for &a in order.iter().rev() {
+            let mut row = vec![0u64; reach.words];
+            for &d in &successors[a] {
+                for (word, &other) in row.iter_mut().zip(reach.row_words(d)) {
+                    *word |= other;
+                }
+                row[d / 64] |= 1 << (d % 64);
+            }
+            reach.bits[a * reach.words..(a + 1) * reach.words].copy_from_slice(&row);
+        }
+        Condensation {
+            members,
+            component,
+            successors,
+            cyclic,
+            reach,
+        }
+    }
+
+    /// Computes the transitive closure of a directed graph as a bit matrix, where row `u` has the bit of `v` set
+    /// when `v` can be reached from `u` by a path of at least one edge. The graph is first condensed into its strongly
+    /// connected components with `kosaraju_algorithm`; the reachable components are then collected in reverse
+    /// topological order with one bitwise OR per word for every edge of the condensation, and shared by all vertices
+    /// of a component. Runs in O(V + E + E' C / 64) time for C components and E' condensed edges, plus the size of the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `adj_list` - A directed graph represented as an adjacency list, as taken by `kosaraju_algorithm`.
+    ///
+    /// # Returns
+    ///
+    /// * `BitMatrix` - The reachability matrix. A vertex reaches itself only when it lies on a cycle or has a self-loop.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //0 -> 1 -> 2 with the cycle 2 -> 3 -> 2
+    /// let adj_list = vec![vec![1], vec![2], vec![3], vec![2]];
+    ///
+    /// let closure = transitive_closure(&adj_list);
+    /// assert_eq!(closure.row(0), vec![1, 2, 3]);
+    /// assert_eq!(closure.row(2), vec![2, 3]);
+    /// assert!(!closure.contains(1, 1));
+    /// ```
+    pub fn transitive_closure(adj_list: &[Vec<usize>]) -> BitMatrix {
+        let n = adj_list.len();
+        let condensation = condense(adj_list);
+        let mut closure = BitMatrix::new(n);
+        for (a, scc) in condensation.members.iter().enumerate() {
+            let mut row = vec![0u64; closure.words];
+            let mut reached = condensation.reach.row(a);
+            if condensation.cyclic[a] {
+                reached.push(a);
+            }
+            for d in reached {
+                for &v in &condensation.members[d] {
+                    row[v / 64] |= 1 << (v % 64);
+                }
+            }
+            for &u in scc {
+                closure.bits[u * closure.words..(u + 1) * closure.words].copy_from_slice(&row);
+            }
+        }
+        closure
+    }
+
+    /// Computes a transitive reduction of a directed graph: a graph with the same reachability and as few edges as possible,
+    /// following Aho, Garey and Ullman. Between two strongly connected components one original edge is kept exactly
+    /// when no other path joins them, and the vertices of every component with more than one vertex are joined
+    /// by a single cycle in increasing order. For a DAG this is the unique minimal subgraph with the same reachability.
+    ///
+    /// # Arguments
+    ///
+    /// * `adj_list` - A directed graph represented as an adjacency list, as taken by `kosaraju_algorithm`.
+    ///
+    /// # Returns
+    ///
+    /// * `reduction` - The adjacency list of the reduced graph, every list in increasing order.
+    ///   Self-loops are kept on vertices outside larger cycles, which they alone make reach themselves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //The edge 0 -> 2 is implied by 0 -> 1 -> 2
+    /// let adj_list = vec![vec![1, 2], vec![2], vec![]];
+    /// assert_eq!(transitive_reduction(&adj_list), vec![vec![1], vec![2], vec![]]);
+    /// ```
+    pub fn transitive_reduction(adj_list: &[Vec<usize>]) -> Vec<Vec<usize>> {
+        let condensation = condense(adj_list);
+        let mut reduction = vec![Vec::new(); adj_list.len()];
+        for scc in &condensation.members {
+            if scc.len() > 1 {
+                for (i, &u) in scc.iter().enumerate() {
+                    reduction[u].push(scc[(i + 1) % scc.len()]);
+                }
+            }
+        }
+
+        //The condensed edge a -> d is redundant when another successor of a reaches d
+        let mut kept: Vec<Vec<usize>> = condensation
+            .successors
+            .iter()
+            .map(|successors| {
+                successors
+                    .iter()
+                    .copied()
+                    .filter(|&d| !successors.iter().any(|&e| e != d && condensation.reach.contains(e, d)))
+                    .collect()
+            })
+            .collect();
+        for (u, adj) in adj_list.iter().enumerate() {
+            let a = condensation.component[u];
+            for &v in adj {
+                let d = condensation.component[v];
+                if u == v && condensation.members[a].len() == 1 {
+                    reduction[u].push(u);
+                } else if let Ok(i) = kept[a].binary_search(&d) {
+                    kept[a].remove(i); //Only the first original edge between two components is kept
+                    reduction[u].push(v);
+                }
+            }
+        }
+        for list in reduction.iter_mut() {
+            list.sort_unstable();
+            list.dedup();
+        }
+        reduction
+    }
+
+    /// Computes the transitive closure and a transitive reduction of a directed graph entered by the user.
+    ///
+    /// # Input
+    ///
+    /// * `Number of vertices` - Number of vertices in the graph.
+    /// * `Number of neighbors for each vertex`
+    /// * `Next neighbor for each vertex`
+    ///
+    /// # Output
+    ///
+    /// Prints the vertices reachable from every vertex and the adjacency list of the reduction
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 3
+    /// Please enter the number of neighbors for vertex 0 : 2
+    /// Please enter the next neighbor for vertex 0 : 1
+    /// Please enter the next neighbor for vertex 0 : 2
+    /// Please enter the number of neighbors for vertex 1 : 1
+    /// Please enter the next neighbor for vertex 1 : 2
+    /// Please enter the number of neighbors for vertex 2 : 0
+    /// ```
+    /// # Sample output
+    /// ```
+    /// Reachable from vertex 0 : [1, 2]
+    /// Reachable from vertex 1 : [2]
+    /// Reachable from vertex 2 : []
+    /// Transitive reduction : [[1], [2], []]
+    /// ```
+    pub fn closure() {
+        println!("******Transitive Closure and Reduction*******");
+        println!("******************");
+        let adj_list = add_neighbors(); // Reading the directed adjacency list from the user
+
+        let closure = transitive_closure(&adj_list);
+        println!("******************");
+        for u in 0..adj_list.len() {
+            println!("Reachable from vertex {} : {:?}", u, closure.row(u));
+        }
+        println!("Transitive reduction : {:?}", transitive_reduction(&adj_list));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::closure::*;
+
+    #[test]
+    fn test_closure_across_words() {
+        //A path of 130 vertices spans three words per row
+        let n = 130;
+        let adj_list: Vec<Vec<usize>> = (0..n).map(|u| if u + 1 < n { vec![u + 1] } else { vec![] }).collect();
+        let closure = transitive_closure(&adj_list);
+        assert_eq!(closure.row(0), (1..n).collect::<Vec<_>>());
+        assert_eq!(closure.row(64), (65..n).collect::<Vec<_>>());
+        assert!(closure.row(n - 1).is_empty());
+        assert!(closure.contains(3, 129) && !closure.contains(129, 3));
+
+        //Closing the path into a cycle makes every vertex reach every vertex
+        let mut cycle = adj_list.clone();
+        cycle[n - 1].push(0);
+        let closure = transitive_closure(&cycle);
+        assert!((0..n).all(|u| closure.row(u).len() == n));
+    }
+
+    #[test]
+    fn test_reduction() {
+        //A DAG with shortcuts: only the covering edges remain
+        let adj_list = vec![vec![1, 2, 3, 4], vec![3, 4], vec![3], vec![4], vec![]];
+        assert_eq!(transitive_reduction(&adj_list), vec![vec![1, 2], vec![3], vec![3], vec![4], vec![]]);
+
+        //Components {0, 1, 2} and {3, 4} with two edges between them and a self-loop on 5
+        let adj_list = vec![vec![1, 2], vec![0, 2, 3], vec![0, 1, 4], vec![4], vec![3, 5], vec![5]];
+        let reduction = transitive_reduction(&adj_list);
+        assert_eq!(reduction, vec![vec![1], vec![2, 3], vec![0], vec![4], vec![3, 5], vec![5]]);
+        assert_eq!(transitive_closure(&reduction), transitive_closure(&adj_list));
+    }
+}
@@ -0,0 +1,352 @@
+pub mod clustering {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::bfs::bfs::{add_edges, Graph};
+    use std::io::{stdin, stdout, Write};
+
+    /// The k-core decomposition computed by `core_decomposition`.
+    #[derive(Debug, PartialEq)]
+    pub struct CoreDecomposition {
+        /// The core number of every vertex: the largest k such that the vertex belongs to the k-core.
+        pub core: Vec<usize>,
+        /// The degeneracy of the graph, the largest core number, or 0 for a graph without vertices.
+        pub degeneracy: usize,
+        /// The vertices in the order they were peeled off. Every vertex has at most `degeneracy` neighbors later in the order.
+        pub order: Vec<usize>,
+    }
+
+    /// Returns the sorted neighbors of every vertex, ignoring self-loops and parallel edges.
+    pub fn simple_neighbors(g: &Graph) -> Vec<Vec<usize>> {
+        g.edges
+            .iter()
+            .enumerate()
+            .map(|(u, adj)| {
+                let mut adj: Vec<usize> = adj.iter().copied().filter(|&v| v != u).collect();
+                adj.sort_unstable();
+                adj.dedup();
+                adj
+            })
+            .collect()
+    }
+
+    /// Counts the triangles every vertex belongs to. Every edge is directed from the endpoint of smaller degree
+    /// to the one of larger degree, so that each triangle is found exactly once, from its lowest vertex, in O(E^1.5) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Self-loops and parallel edges are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `triangles` - The number of triangles through every vertex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //A square with the diagonal 0 - 2
+    /// let mut g = Graph::new(4);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)] {
+    ///     g.add_edge(u, v);
+    /// }
+    /// assert_eq!(triangles(&g), vec![2, 1, 2, 1]);
+    /// ```
+    pub fn triangles(g: &Graph) -> Vec<usize> {
+        let adj = simple_neighbors(g);
+        let rank = |v: usize| (adj[v].len(), v);
+        let forward: Vec<Vec<usize>> = adj
+            .iter()
+            .enumerate()
+            .map(|(u, neighbors)| neighbors.iter().copied().filter(|&v| rank(v) > rank(u)).collect())
+            .collect();
+
+        let mut count = vec![0; adj.len()];
+        let mut marked = vec![false; adj.len()];
+        for u in 0..adj.len() {
+            for &v in &forward[u] {
+                marked[v] = true;
+            }
+            for &v in &forward[u] {
+                for &w in &forward[v] {
+                    if marked[w] {
+                        count[u] += 1;
+                        count[v] += 1;
+                        count[w] += 1;
+                    }
+                }
+            }
+            for &v in &forward[u] {
+                marked[v] = false;
+            }
+        }
+        count
+    }
+
+    /// Counts the triangles of the whole graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)] {
+    ///     g.add_edge(u, v);
+    /// }
+    /// assert_eq!(triangle_count(&g), 2);
+    /// ```
+    pub fn triangle_count(g: &Graph) -> usize {
+        triangles(g).iter().sum::<usize>() / 3
+    }
+
+    /// Computes the local clustering coefficient of every vertex: the fraction of pairs of its neighbors that are
+    /// themselves joined by an edge. Vertices with fewer than two neighbors have coefficient 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Self-loops and parallel edges are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `clustering` - The coefficient of every vertex, between 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)] {
+    ///     g.add_edge(u, v);
+    /// }
+    /// assert_eq!(local_clustering(&g), vec![2.0 / 3.0, 1.0, 2.0 / 3.0, 1.0]);
+    /// ```
+    pub fn local_clustering(g: &Graph) -> Vec<f64> {
+        let adj = simple_neighbors(g);
+        triangles(g)
+            .iter()
+            .zip(&adj)
+            .map(|(&t, neighbors)| {
+                let d = neighbors.len() as f64;
+                if neighbors.len() < 2 {
+                    0.0
+                } else {
+                    2.0 * t as f64 / (d * (d - 1.0))
+                }
+            })
+            .collect()
+    }
+
+    /// Computes the average of the local clustering coefficients over all vertices, or 0 for a graph without vertices.
+    pub fn average_clustering(g: &Graph) -> f64 {
+        let clustering = local_clustering(g);
+        if clustering.is_empty() {
+            return 0.0;
+        }
+        clustering.iter().sum::<f64>() / clustering.len() as f64
+    }
+
+    /// Computes the core number of every vertex with the bucket algorithm of Batagelj and Zaversnik:
+    /// the vertex of smallest remaining degree is removed repeatedly, and its degree at removal is its core number.
+    /// Runs in O(V + E) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Self-loops and parallel edges are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `CoreDecomposition` - The core numbers, the degeneracy and the degeneracy ordering.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //A triangle with a pendant vertex 3
+    /// let mut g = Graph::new(4);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+    ///     g.add_edge(u, v);
+    /// }
+    /// let cores = core_decomposition(&g);
+    /// assert_eq!(cores.core, vec![2, 2, 2, 1]);
+    /// assert_eq!(cores.degeneracy, 2);
+    /// assert_eq!(cores.order[0], 3);
+    /// ```
+    pub fn core_decomposition(g: &Graph) -> CoreDecomposition {
+        let adj = simple_neighbors(g);
+        let n = adj.len();
+        let mut degree: Vec<usize> = adj.iter().map(|neighbors| neighbors.len()).collect();
+        let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); n.max(1)];
+        for v in (0..n).rev() {
+            buckets[degree[v]].push(v);
+        }
+
+        //Buckets hold stale entries for vertices whose degree has dropped since, which are skipped.
+        //No remaining degree falls below the current bucket, so the scan never has to go back.
+        let mut core = vec![0; n];
+        let mut removed = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+        let mut d = 0;
+        while order.len() < n {
+            match buckets[d].pop() {
+                None => d += 1,
+                Some(v) if removed[v] || degree[v] != d => {}
+                Some(v) => {
+                    removed[v] = true;
+                    core[v] = d;
+                    order.push(v);
+                    for &u in &adj[v] {
+                        if !removed[u] && degree[u] > d {
+                            degree[u] -= 1;
+                            buckets[degree[u]].push(u);
+                        }
+                    }
+                }
+            }
+        }
+        CoreDecomposition {
+            degeneracy: core.iter().copied().max().unwrap_or(0),
+            core,
+            order,
+        }
+    }
+
+    /// Returns the vertices of the k-core, the largest subgraph in which every vertex has at least `k` neighbors,
+    /// in increasing order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+    ///     g.add_edge(u, v);
+    /// }
+    /// assert_eq!(k_core(&g, 2), vec![0, 1, 2]);
+    /// ```
+    pub fn k_core(g: &Graph, k: usize) -> Vec<usize> {
+        let cores = core_decomposition(g);
+        (0..cores.core.len()).filter(|&v| cores.core[v] >= k).collect()
+    }
+
+    /// Computes triangle counts, clustering coefficients and the core decomposition of a graph entered by the user.
+    ///
+    /// # Input
+    /// * `no_of_vertices` - Input the number of vertices in the graph
+    /// * `no_of_edges` - Input the number of edges in the graph
+    /// * `source` - The source vertex of an edge in the graph
+    /// * `destination` - The destination of an edge in the graph
+    ///
+    /// # Output
+    ///
+    /// Prints the statistics of every vertex and of the whole graph
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 4
+    ///Please Enter Number of edges in the graph : 4
+    ///Source : 0
+    ///Destination : 1
+    ///Source : 1
+    ///Destination : 2
+    ///Source : 2
+    ///Destination : 0
+    ///Source : 2
+    ///Destination : 3
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///Triangles : [1, 1, 1, 0], 1 in total
+    ///Clustering : [1.000, 1.000, 0.333, 0.000]
+    ///Average clustering : 0.583
+    ///Core numbers : [2, 2, 2, 1]
+    ///Degeneracy : 2, with core [0, 1, 2]
+    /// ```
+    pub fn clustering() {
+        //read the number of vertices from the console
+        let mut vertex = String::new();
+        println!("*****Triangles, Clustering and Cores********");
+        println!("****************************************************");
+        print!("Please Enter Number of Vertices : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut vertex)
+            .expect("Enter valid number of vertices");
+        let vertices: usize = vertex.trim().parse().expect("Invalid input");
+        //get number of edges in the graph
+        let mut n_edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut n_edges).expect("Enter Valid Input");
+        let n_edges: i32 = n_edges.trim().parse().expect("Invalid input for edges");
+        //assign the vertices to each edge from the console
+        let g = add_edges(vertices, n_edges);
+
+        println!("******************");
+        println!("Triangles : {:?}, {} in total", triangles(&g), triangle_count(&g));
+        println!("Clustering : {:.3?}", local_clustering(&g));
+        println!("Average clustering : {:.3}", average_clustering(&g));
+        let cores = core_decomposition(&g);
+        println!("Core numbers : {:?}", cores.core);
+        println!(
+            "Degeneracy : {}, with core {:?}",
+            cores.degeneracy,
+            k_core(&g, cores.degeneracy)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clustering::*;
+    use crate::list_of_algorithms::bfs::bfs::Graph;
+
+    fn complete(n: usize) -> Graph {
+        let mut g = Graph::new(n);
+        for u in 0..n {
+            for v in u + 1..n {
+                g.add_edge(u, v);
+            }
+        }
+        g
+    }
+
+    #[test]
+    fn test_triangles_and_clustering() {
+        //K5 has C(5, 3) triangles, six through every vertex
+        let g = complete(5);
+        assert_eq!(triangles(&g), vec![6; 5]);
+        assert_eq!(triangle_count(&g), 10);
+        assert_eq!(average_clustering(&g), 1.0);
+
+        //Self-loops and parallel edges do not add triangles, a star has none
+        let mut g = Graph::new(5);
+        for (u, v) in [(0, 1), (0, 2), (0, 3), (0, 4), (0, 1), (2, 2)] {
+            g.add_edge(u, v);
+        }
+        assert_eq!(triangle_count(&g), 0);
+        assert_eq!(local_clustering(&g), vec![0.0; 5]);
+        g.add_edge(1, 2);
+        assert_eq!(triangles(&g), vec![1, 1, 1, 0, 0]);
+        assert_eq!(local_clustering(&g)[0], 1.0 / 6.0);
+        assert_eq!(average_clustering(&Graph::new(0)), 0.0);
+    }
+
+    #[test]
+    fn test_core_decomposition() {
+        //K4 with a path 3 - 4 - 5 attached and an isolated vertex 6
+        let mut g = complete(4);
+        g.vertices = 7;
+        g.edges.resize(7, Vec::new());
+        g.add_edge(3, 4);
+        g.add_edge(4, 5);
+        let cores = core_decomposition(&g);
+        assert_eq!(cores.core, vec![3, 3, 3, 3, 1, 1, 0]);
+        assert_eq!(cores.degeneracy, 3);
+        assert_eq!(k_core(&g, 3), vec![0, 1, 2, 3]);
+        assert_eq!(k_core(&g, 1).len(), 6);
+
+        //Every vertex has at most `degeneracy` neighbors later in the order
+        let adj = simple_neighbors(&g);
+        let mut position = [0; 7];
+        for (i, &v) in cores.order.iter().enumerate() {
+            position[v] = i;
+        }
+        for v in 0..7 {
+            assert!(adj[v].iter().filter(|&&u| position[u] > position[v]).count() <= cores.degeneracy);
+        }
+        assert_eq!(core_decomposition(&Graph::new(0)).degeneracy, 0);
+    }
+}
@@ -0,0 +1,310 @@
+pub mod coloring {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::bfs::bfs::{add_edges, Graph};
+    use crate::list_of_algorithms::cliques::cliques::maximum_clique;
+    use crate::list_of_algorithms::clustering::clustering::{core_decomposition, simple_neighbors};
+    use std::io::{stdin, stdout, Write};
+
+    /// The order in which `greedy_coloring` colours the vertices.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum GreedyOrder {
+        /// By decreasing degree, ties by increasing vertex.
+        LargestFirst,
+        /// The reverse of the degeneracy ordering, so that every vertex has at most `degeneracy` neighbors coloured before it
+        /// and at most `degeneracy + 1` colours are used.
+        SmallestLast,
+    }
+
+    /// Returns the number of colours used by a colouring.
+    pub fn color_count(colors: &[usize]) -> usize {
+        colors.iter().map(|&c| c + 1).max().unwrap_or(0)
+    }
+
+    //Gives v the smallest colour not used by a coloured neighbor
+    fn smallest_free(adj: &[Vec<usize>], colors: &[usize], v: usize) -> usize {
+        let mut taken = vec![false; adj[v].len() + 1];
+        for &u in &adj[v] {
+            if colors[u] < taken.len() {
+                taken[colors[u]] = true;
+            }
+        }
+        taken.iter().position(|&t| !t).unwrap_or(taken.len())
+    }
+
+    /// Colours the vertices one at a time in the given order, each with the smallest colour not used by its neighbors.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Self-loops and parallel edges are ignored.
+    /// * `order` - The strategy choosing the order of the vertices.
+    ///
+    /// # Returns
+    ///
+    /// * `colors` - The colour of every vertex, numbered from 0. Adjacent vertices have different colours.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //A star with center 0 and the extra edge 1 - 2
+    /// let mut g = Graph::new(5);
+    /// for (u, v) in [(0, 1), (0, 2), (0, 3), (0, 4), (1, 2)] {
+    ///     g.add_edge(u, v);
+    /// }
+    /// assert_eq!(greedy_coloring(&g, GreedyOrder::LargestFirst), vec![0, 1, 2, 1, 1]);
+    /// ```
+    pub fn greedy_coloring(g: &Graph, order: GreedyOrder) -> Vec<usize> {
+        let adj = simple_neighbors(g);
+        let vertices: Vec<usize> = match order {
+            GreedyOrder::LargestFirst => {
+                let mut vertices: Vec<usize> = (0..adj.len()).collect();
+                vertices.sort_by_key(|&v| std::cmp::Reverse(adj[v].len()));
+                vertices
+            }
+            GreedyOrder::SmallestLast => core_decomposition(g).order.into_iter().rev().collect(),
+        };
+        let mut colors = vec![usize::MAX; adj.len()];
+        for v in vertices {
+            colors[v] = smallest_free(&adj, &colors, v);
+        }
+        colors
+    }
+
+    //Picks the uncoloured vertex with the most distinct neighbor colours, ties by most uncoloured neighbors, then smallest
+    fn most_saturated(adj: &[Vec<usize>], colors: &[usize]) -> Option<usize> {
+        (0..adj.len())
+            .filter(|&v| colors[v] == usize::MAX)
+            .max_by_key(|&v| {
+                let mut seen: Vec<usize> = adj[v].iter().map(|&u| colors[u]).filter(|&c| c != usize::MAX).collect();
+                seen.sort_unstable();
+                seen.dedup();
+                let uncolored = adj[v].iter().filter(|&&u| colors[u] == usize::MAX).count();
+                (seen.len(), uncolored, std::cmp::Reverse(v))
+            })
+    }
+
+    /// DSatur, Brélaz's heuristic: the next vertex coloured is always the one whose neighbors already use the most
+    /// distinct colours, ties broken by the number of uncoloured neighbors. Optimal on bipartite graphs, cycles and wheels.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Self-loops and parallel edges are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `colors` - The colour of every vertex, numbered from 0. Adjacent vertices have different colours.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //A cycle of six vertices
+    /// let mut g = Graph::new(6);
+    /// for u in 0..6 {
+    ///     g.add_edge(u, (u + 1) % 6);
+    /// }
+    /// assert_eq!(dsatur(&g), vec![0, 1, 0, 1, 0, 1]);
+    /// ```
+    pub fn dsatur(g: &Graph) -> Vec<usize> {
+        let adj = simple_neighbors(g);
+        let mut colors = vec![usize::MAX; adj.len()];
+        while let Some(v) = most_saturated(&adj, &colors) {
+            colors[v] = smallest_free(&adj, &colors, v);
+        }
+        colors
+    }
+
+    //State of the backtracking search for an optimal colouring
+    struct Search<'a> {
+        adj: &'a [Vec<usize>],
+        colors: Vec<usize>,
+        best: Vec<usize>,
+        best_count: usize,
+        lower_bound: usize,
+    }
+
+    impl Search<'_> {
+        fn extend(&mut self, used: usize) {
+            let v = match most_saturated(self.adj, &self.colors) {
+                Some(v) => v,
+                None => {
+                    self.best = self.colors.clone();
+                    self.best_count = used;
+                    return;
+                }
+            };
+            //Colours up to one new colour are tried, as long as the count stays below the best colouring
+            for c in 0..=used {
+                if c + 1 >= self.best_count || self.best_count == self.lower_bound {
+                    return;
+                }
+                if self.adj[v].iter().all(|&u| self.colors[u] != c) {
+                    self.colors[v] = c;
+                    self.extend(used.max(c + 1));
+                    self.colors[v] = usize::MAX;
+                }
+            }
+        }
+    }
+
+    /// Finds a colouring with the fewest colours, the chromatic number, by backtracking in DSatur order.
+    /// The search starts from the DSatur colouring, only tries colourings with fewer colours than the best found,
+    /// and stops as soon as the count reaches the size of a maximum clique. The running time is exponential,
+    /// so this is meant for small graphs.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Self-loops and parallel edges are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `colors` - The colour of every vertex, numbered from 0, using as few colours as possible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //The Petersen graph needs three colours
+    /// let mut g = Graph::new(10);
+    /// for u in 0..5 {
+    ///     g.add_edge(u, (u + 1) % 5);
+    ///     g.add_edge(u, u + 5);
+    ///     g.add_edge(u + 5, (u + 2) % 5 + 5);
+    /// }
+    /// assert_eq!(color_count(&exact_coloring(&g)), 3);
+    /// ```
+    pub fn exact_coloring(g: &Graph) -> Vec<usize> {
+        let adj = simple_neighbors(g);
+        let best = dsatur(g);
+        let mut search = Search {
+            adj: &adj,
+            colors: vec![usize::MAX; adj.len()],
+            best_count: color_count(&best),
+            best,
+            lower_bound: maximum_clique(g, None).vertices.len(),
+        };
+        search.extend(0);
+        search.best
+    }
+
+    /// Colours a graph entered by the user with every strategy.
+    ///
+    /// # Input
+    /// * `no_of_vertices` - Input the number of vertices in the graph
+    /// * `no_of_edges` - Input the number of edges in the graph
+    /// * `source` - The source vertex of an edge in the graph
+    /// * `destination` - The destination of an edge in the graph
+    ///
+    /// # Output
+    ///
+    /// Prints the colour of every vertex and the number of colours for each strategy
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 4
+    ///Please Enter Number of edges in the graph : 4
+    ///Source : 0
+    ///Destination : 1
+    ///Source : 1
+    ///Destination : 2
+    ///Source : 2
+    ///Destination : 0
+    ///Source : 2
+    ///Destination : 3
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///Largest first : [1, 2, 0, 1] using 3 colours
+    ///Smallest last : [1, 0, 2, 0] using 3 colours
+    ///DSatur : [1, 2, 0, 1] using 3 colours
+    ///Exact : [1, 2, 0, 1] using 3 colours
+    /// ```
+    pub fn coloring() {
+        //read the number of vertices from the console
+        let mut vertex = String::new();
+        println!("*****Graph Colouring********");
+        println!("****************************************************");
+        print!("Please Enter Number of Vertices : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut vertex)
+            .expect("Enter valid number of vertices");
+        let vertices: usize = vertex.trim().parse().expect("Invalid input");
+        //get number of edges in the graph
+        let mut n_edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut n_edges).expect("Enter Valid Input");
+        let n_edges: i32 = n_edges.trim().parse().expect("Invalid input for edges");
+        //assign the vertices to each edge from the console
+        let g = add_edges(vertices, n_edges);
+
+        println!("******************");
+        for (name, colors) in [
+            ("Largest first", greedy_coloring(&g, GreedyOrder::LargestFirst)),
+            ("Smallest last", greedy_coloring(&g, GreedyOrder::SmallestLast)),
+            ("DSatur", dsatur(&g)),
+            ("Exact", exact_coloring(&g)),
+        ] {
+            println!("{} : {:?} using {} colours", name, colors, color_count(&colors));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::coloring::*;
+    use crate::list_of_algorithms::bfs::bfs::Graph;
+
+    fn is_proper(g: &Graph, colors: &[usize]) -> bool {
+        (0..g.edges.len()).all(|u| g.edges[u].iter().all(|&v| u == v || colors[u] != colors[v]))
+    }
+
+    #[test]
+    fn test_heuristics_are_proper() {
+        //A crown graph: greedy in vertex order would need n colours, but it is bipartite
+        let n = 5;
+        let mut g = Graph::new(2 * n);
+        for u in 0..n {
+            for v in 0..n {
+                if u != v {
+                    g.add_edge(2 * u, 2 * v + 1);
+                }
+            }
+        }
+        g.add_edge(3, 3);
+        for colors in [
+            greedy_coloring(&g, GreedyOrder::LargestFirst),
+            greedy_coloring(&g, GreedyOrder::SmallestLast),
+            dsatur(&g),
+        ] {
+            assert!(is_proper(&g, &colors));
+        }
+        assert_eq!(color_count(&dsatur(&g)), 2);
+        assert_eq!(color_count(&exact_coloring(&g)), 2);
+        assert!(dsatur(&Graph::new(0)).is_empty());
+    }
+
+    #[test]
+    fn test_exact_coloring() {
+        //An odd wheel: a 5-cycle needs three colours and the hub a fourth, with no K4 inside
+        let mut g = Graph::new(6);
+        for u in 0..5 {
+            g.add_edge(u, (u + 1) % 5);
+            g.add_edge(u, 5);
+        }
+        let colors = exact_coloring(&g);
+        assert!(is_proper(&g, &colors));
+        assert_eq!(color_count(&colors), 4);
+
+        //The Mycielski graph of a 5-cycle, the Grötzsch graph, is triangle-free with chromatic number 4
+        let mut g = Graph::new(11);
+        for u in 0..5 {
+            g.add_edge(u, (u + 1) % 5);
+            g.add_edge(u + 5, (u + 1) % 5);
+            g.add_edge(u + 5, (u + 4) % 5);
+            g.add_edge(u + 5, 10);
+        }
+        let colors = exact_coloring(&g);
+        assert!(is_proper(&g, &colors));
+        assert_eq!(color_count(&colors), 4);
+        assert_eq!(color_count(&exact_coloring(&Graph::new(3))), 1);
+    }
+}
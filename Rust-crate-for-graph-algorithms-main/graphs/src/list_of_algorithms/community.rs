@@ -0,0 +1,430 @@
+pub mod community {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::dijkstras::dijkstras::{add_edges, Graph};
+    use crate::list_of_algorithms::random::random::XorShift;
+    use std::io::{stdin, stdout, Write};
+
+    /// A division of the vertices into communities.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Partition {
+        /// The community of every vertex, numbered from 0 in order of their smallest vertex.
+        pub membership: Vec<usize>,
+        /// The vertices of every community in increasing order.
+        pub communities: Vec<Vec<usize>>,
+        /// The modularity of the partition, see `modularity`.
+        pub modularity: f64,
+    }
+
+    //Renumbers arbitrary labels in order of their first vertex and collects the communities
+    fn partition(g: &Graph, labels: &[usize]) -> Partition {
+        let mut number = vec![usize::MAX; labels.len()];
+        let mut membership = Vec::with_capacity(labels.len());
+        let mut communities: Vec<Vec<usize>> = Vec::new();
+        for (v, &label) in labels.iter().enumerate() {
+            if number[label] == usize::MAX {
+                number[label] = communities.len();
+                communities.push(Vec::new());
+            }
+            membership.push(number[label]);
+            communities[number[label]].push(v);
+        }
+        let modularity = modularity(g, &membership);
+        Partition {
+            membership,
+            communities,
+            modularity,
+        }
+    }
+
+    /// Computes the modularity of a partition: the fraction of the edge weight that falls inside communities,
+    /// minus the fraction expected when the edges are rewired at random keeping the weighted degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`, with positive weights.
+    /// * `membership` - The community of every vertex, as numbers below the number of vertices.
+    ///
+    /// # Returns
+    ///
+    /// * `modularity` - A value between -1/2 and 1, or 0 for a graph without edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// g.add_edge(0, 1, 1);
+    /// g.add_edge(2, 3, 1);
+    ///
+    /// assert_eq!(modularity(&g, &[0, 0, 1, 1]), 0.5);
+    /// assert_eq!(modularity(&g, &[0, 0, 0, 0]), 0.0);
+    /// ```
+    pub fn modularity(g: &Graph, membership: &[usize]) -> f64 {
+        let n = g.vertex_count();
+        let mut inside = vec![0.0; n];
+        let mut degree = vec![0.0; n];
+        let mut total = 0.0;
+        for (u, v, w) in g.edge_list() {
+            let w = w as f64;
+            total += w;
+            degree[membership[u]] += w;
+            degree[membership[v]] += w;
+            if membership[u] == membership[v] {
+                inside[membership[u]] += w;
+            }
+        }
+        if total == 0.0 {
+            return 0.0;
+        }
+        (0..n)
+            .map(|c| inside[c] / total - (degree[c] / (2.0 * total)).powi(2))
+            .sum()
+    }
+
+    /// Asynchronous label propagation. Every vertex starts in a community of its own, then the vertices are visited
+    /// in random order, each adopting the label with the largest total edge weight among its neighbors,
+    /// until every vertex already has such a label. A vertex keeps its label on a tie that includes it
+    /// and otherwise breaks ties at random, so every change increases the weight inside communities and the search ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`, with positive weights.
+    /// * `seed` - The seed of the random visiting order and tie breaks, so that results are reproducible.
+    ///
+    /// # Returns
+    ///
+    /// * `Partition` - The communities found and their modularity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //Two triangles joined by the edge 2 - 3
+    /// let mut g = Graph::new(6);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+    ///     g.add_edge(u, v, 1);
+    /// }
+    ///
+    /// let result = label_propagation(&g, 7);
+    /// assert_eq!(result.communities, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+    /// ```
+    pub fn label_propagation(g: &Graph, seed: u64) -> Partition {
+        let n = g.vertex_count();
+        let mut rng = XorShift::new(seed);
+        let mut labels: Vec<usize> = (0..n).collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        let mut weight = vec![0.0; n]; //weight of every label around the current vertex
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for i in (1..n).rev() {
+                order.swap(i, rng.below(i + 1));
+            }
+            for &u in &order {
+                let mut seen = Vec::new();
+                for (v, w) in g.neighbors(u) {
+                    if v != u {
+                        if weight[labels[v]] == 0.0 {
+                            seen.push(labels[v]);
+                        }
+                        weight[labels[v]] += w as f64;
+                    }
+                }
+                let best = seen.iter().map(|&l| weight[l]).fold(0.0, f64::max);
+                let ties: Vec<usize> = seen.iter().copied().filter(|&l| weight[l] == best).collect();
+                if !ties.is_empty() && !ties.contains(&labels[u]) {
+                    labels[u] = ties[rng.below(ties.len())];
+                    changed = true;
+                }
+                for l in seen {
+                    weight[l] = 0.0;
+                }
+            }
+        }
+        partition(g, &labels)
+    }
+
+    /// The Louvain method. Vertices are moved one at a time to the neighboring community that increases
+    /// the modularity most, until no move helps; then every community is merged into a single vertex,
+    /// with the edges inside it becoming a self-loop, and the process repeats on the smaller graph
+    /// until no vertex moves at all.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`, with positive weights.
+    ///
+    /// # Returns
+    ///
+    /// * `Partition` - The communities found and their modularity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //Two triangles joined by the edge 2 - 3
+    /// let mut g = Graph::new(6);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+    ///     g.add_edge(u, v, 1);
+    /// }
+    ///
+    /// let result = louvain(&g);
+    /// assert_eq!(result.membership, vec![0, 0, 0, 1, 1, 1]);
+    /// assert!((result.modularity - 5.0 / 14.0).abs() < 1e-12);
+    /// ```
+    pub fn louvain(g: &Graph) -> Partition {
+        let mut membership: Vec<usize> = (0..g.vertex_count()).collect();
+        //The graph of the current level as edges (u, v, w) with u <= v
+        let mut n = g.vertex_count();
+        let mut edges: Vec<(usize, usize, f64)> =
+            g.edge_list().into_iter().map(|(u, v, w)| (u, v, w as f64)).collect();
+        let total: f64 = edges.iter().map(|e| e.2).sum();
+        if total == 0.0 {
+            return partition(g, &membership);
+        }
+
+        loop {
+            let mut adj: Vec<Vec<(usize, f64)>> = vec![Vec::new(); n];
+            let mut degree = vec![0.0; n];
+            for &(u, v, w) in &edges {
+                degree[u] += w;
+                degree[v] += w;
+                if u != v {
+                    adj[u].push((v, w));
+                    adj[v].push((u, w));
+                }
+            }
+
+            //Local moves: the gain of joining community c is proportional to k_i,c - tot_c * k_i / 2m
+            let mut community: Vec<usize> = (0..n).collect();
+            let mut tot = degree.clone();
+            let mut link = vec![0.0; n]; //weight from the current vertex to every community
+            let mut moved = false;
+            let mut improved = true;
+            while improved {
+                improved = false;
+                for u in 0..n {
+                    let own = community[u];
+                    tot[own] -= degree[u];
+                    let mut seen = vec![own];
+                    for &(v, w) in &adj[u] {
+                        if link[community[v]] == 0.0 && community[v] != own {
+                            seen.push(community[v]);
+                        }
+                        link[community[v]] += w;
+                    }
+                    let gain = |c: usize| link[c] - tot[c] * degree[u] / (2.0 * total);
+                    let mut best = own;
+                    for &c in &seen[1..] {
+                        if gain(c) > gain(best) + 1e-12 {
+                            best = c;
+                        }
+                    }
+                    for &c in &seen {
+                        link[c] = 0.0;
+                    }
+                    tot[best] += degree[u];
+                    if best != own {
+                        community[u] = best;
+                        improved = true;
+                        moved = true;
+                    }
+                }
+            }
+            if !moved {
+                break;
+            }
+
+            //Aggregation: every community becomes a vertex of the next level
+            let mut number = vec![usize::MAX; n];
+            let mut count = 0;
+            for c in community.iter_mut() {
+                if number[*c] == usize::MAX {
+                    number[*c] = count;
+                    count += 1;
+                }
+                *c = number[*c];
+            }
+            for m in membership.iter_mut() {
+                *m = community[*m];
+            }
+            let mut merged: Vec<(usize, usize, f64)> = edges
+                .iter()
+                .map(|&(u, v, w)| {
+                    let (a, b) = (community[u], community[v]);
+                    (a.min(b), a.max(b), w)
+                })
+                .collect();
+            merged.sort_by_key(|e| (e.0, e.1));
+            edges = Vec::new();
+            for (u, v, w) in merged {
+                match edges.last_mut() {
+                    Some(last) if (last.0, last.1) == (u, v) => last.2 += w,
+                    _ => edges.push((u, v, w)),
+                }
+            }
+            n = count;
+        }
+        partition(g, &membership)
+    }
+
+    /// Finds communities of a weighted undirected graph entered by the user with label propagation and Louvain.
+    ///
+    /// # Inputs
+    ///
+    /// * `vertices` - Total number of vertices in the graph.
+    ///
+    /// * `edges` - Total Number of edges in the graph.
+    ///
+    /// * `Source(s) Destination(d) Weight(w) ` - Source, Destination and Weight for each edge in the graph.
+    ///
+    /// * `seed` - The seed of the random choices of label propagation.
+    ///
+    /// # Output
+    ///
+    /// Prints the communities found by each method and their modularity.
+    ///
+    /// # Sample Input
+    /// ```
+    /// Please Enter Number of Vertices in the Graph : 4
+    /// Please Enter Number of edges in the graph : 3
+    /// Please Enter Edge 1 values
+    /// Source : 0
+    /// Destination : 1
+    /// Weight(>0) : 5
+    /// Please Enter Edge 2 values
+    /// Source : 1
+    /// Destination : 2
+    /// Weight(>0) : 1
+    /// Please Enter Edge 3 values
+    /// Source : 2
+    /// Destination : 3
+    /// Weight(>0) : 5
+    /// Please Enter Random Seed : 1
+    /// ```
+    /// # Sample Output
+    /// ```
+    /// Label propagation : [[0, 1], [2, 3]] with modularity 0.409
+    /// Louvain : [[0, 1], [2, 3]] with modularity 0.409
+    /// ```
+    pub fn communities() {
+        // Create an empty string to store the number of vertices
+        let mut ve = String::new();
+
+        // Printing the introduction message
+        println!("******Community Detection*******");
+        println!("******************");
+
+        // Prompting user to input number of vertices in the Graph
+        print!("Please Enter Number of Vertices in the Graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut ve)
+            .expect("Please Enter Valid number for vertices.");
+        let vertices: usize = ve
+            .trim()
+            .parse()
+            .expect("Invalid input for number of vertices");
+
+        // Prompting user to input the number of edges
+        let mut edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut edges)
+            .expect("Please Enter Valid Input for number of Edges.");
+        let edges: i32 = edges
+            .trim()
+            .parse()
+            .expect("Invalid input for number of edges");
+
+        // Reading all edges source, destination and weight from the user
+        let g = add_edges(vertices, edges);
+
+        // Prompting user to input the seed of label propagation
+        let mut seed = String::new();
+        print!("Please Enter Random Seed : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut seed)
+            .expect("Please Enter Valid Input for seed.");
+        let seed: u64 = seed.trim().parse().expect("Invalid input for seed");
+
+        println!("******************");
+        for (name, result) in [
+            ("Label propagation", label_propagation(&g, seed)),
+            ("Louvain", louvain(&g)),
+        ] {
+            println!(
+                "{} : {:?} with modularity {:.3}",
+                name, result.communities, result.modularity
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::community::*;
+    use crate::list_of_algorithms::dijkstras::dijkstras::Graph;
+
+    //k cliques of size s in a ring, neighboring cliques joined by a single edge
+    fn ring_of_cliques(k: usize, s: usize) -> Graph {
+        let mut g = Graph::new(k * s);
+        for c in 0..k {
+            for i in 0..s {
+                for j in i + 1..s {
+                    g.add_edge(c * s + i, c * s + j, 1);
+                }
+            }
+            g.add_edge(c * s, ((c + 1) % k) * s + 1, 1);
+        }
+        g
+    }
+
+    #[test]
+    fn test_modularity() {
+        let mut g = Graph::new(6);
+        for (u, v) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+            g.add_edge(u, v, 1);
+        }
+        //Each triangle keeps 3 of the 7 edges and half of the degree
+        let q = modularity(&g, &[0, 0, 0, 1, 1, 1]);
+        assert!((q - (6.0 / 7.0 - 0.5)).abs() < 1e-12);
+        assert_eq!(modularity(&g, &[0; 6]), 0.0);
+        assert!(modularity(&g, &[0, 1, 2, 3, 4, 5]) < 0.0);
+        assert_eq!(modularity(&Graph::new(3), &[0, 1, 2]), 0.0);
+    }
+
+    #[test]
+    fn test_louvain_ring_of_cliques() {
+        let g = ring_of_cliques(6, 5);
+        let result = louvain(&g);
+        assert_eq!(result.communities.len(), 6);
+        for (c, members) in result.communities.iter().enumerate() {
+            assert_eq!(*members, (5 * c..5 * c + 5).collect::<Vec<_>>());
+        }
+        assert!((result.modularity - modularity(&g, &result.membership)).abs() < 1e-12);
+
+        //Heavy edges pull the path 0 - 1 - 2 - 3 apart in the middle
+        let mut g = Graph::new(4);
+        g.add_edge(0, 1, 5);
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 5);
+        assert_eq!(louvain(&g).membership, vec![0, 0, 1, 1]);
+        assert_eq!(louvain(&Graph::new(2)).communities, vec![vec![0], vec![1]]);
+    }
+
+    #[test]
+    fn test_label_propagation() {
+        let g = ring_of_cliques(4, 6);
+        for seed in 0..20 {
+            let result = label_propagation(&g, seed);
+            assert_eq!(result, label_propagation(&g, seed));
+            //Within a clique only a single label is stable
+            for c in 0..4 {
+                assert!((6 * c..6 * c + 6).all(|v| result.membership[v] == result.membership[6 * c + 2]));
+            }
+        }
+        //Isolated vertices stay alone
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1, 1);
+        assert_eq!(label_propagation(&g, 3).communities, vec![vec![0, 1], vec![2]]);
+    }
+}
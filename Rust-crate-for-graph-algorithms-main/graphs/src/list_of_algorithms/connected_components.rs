@@ -0,0 +1,401 @@
+pub mod connected_components {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::bfs::bfs::Graph;
+    use crate::list_of_algorithms::kosaraju::kosaraju::{add_neighbors, kosaraju_algorithm};
+    use std::{
+        collections::VecDeque,
+        io::{stdin, stdout, Write},
+    };
+
+    /// The connected components of an undirected graph.
+    pub struct Components {
+        /// The component label of every vertex. Labels are numbered from 0 in order of the smallest vertex of each component.
+        pub labels: Vec<usize>,
+        /// The number of vertices in each component, indexed by label.
+        pub sizes: Vec<usize>,
+    }
+
+    impl Components {
+        /// Returns the number of connected components.
+        pub fn count(&self) -> usize {
+            self.sizes.len()
+        }
+
+        /// Returns the vertices of every component in sorted order, indexed by label.
+        pub fn members(&self) -> Vec<Vec<usize>> {
+            let mut members = vec![Vec::new(); self.sizes.len()];
+            for (u, &label) in self.labels.iter().enumerate() {
+                members[label].push(u); //Vertices are visited in increasing order so every list stays sorted
+            }
+            members
+        }
+    }
+
+    /// Finds the connected components of an undirected graph with a Breadth-First Search from every unlabelled vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`.
+    ///
+    /// # Returns
+    ///
+    /// * `Components` - The component label of every vertex and the size of every component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(5);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(3, 4);
+    ///
+    /// let components = connected_components(&g);
+    /// assert_eq!(components.labels, vec![0, 0, 1, 2, 2]);
+    /// assert_eq!(components.sizes, vec![2, 1, 2]);
+    /// ```
+    pub fn connected_components(g: &Graph) -> Components {
+        let mut labels = vec![usize::MAX; g.edges.len()]; //usize::MAX marks a vertex that has no label yet
+        let mut sizes = Vec::new();
+        let mut queue = VecDeque::new();
+
+        for start in 0..g.edges.len() {
+            if labels[start] != usize::MAX {
+                continue;
+            }
+            //Every unlabelled vertex starts a new component
+            let label = sizes.len();
+            let mut size = 0;
+            labels[start] = label;
+            queue.push_back(start);
+            while let Some(u) = queue.pop_front() {
+                size += 1;
+                for &v in &g.edges[u] {
+                    if labels[v] == usize::MAX {
+                        labels[v] = label;
+                        queue.push_back(v);
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+
+        Components { labels, sizes }
+    }
+
+    /// A disjoint-set (union-find) structure with path compression and union by rank.
+    /// Elements are the integers `0..n`, which makes it suitable for tracking connectivity while edges are added one by one.
+    pub struct UnionFind {
+        parent: Vec<usize>, //parent of every element, roots are their own parent
+        rank: Vec<usize>,   //upper bound on the height of the tree below every root
+        size: Vec<usize>,   //number of elements in the set of every root
+        sets: usize,        //number of disjoint sets
+    }
+
+    impl UnionFind {
+        /// Creates `n` singleton sets `{0}, {1}, ..., {n - 1}`.
+        pub fn new(n: usize) -> Self {
+            UnionFind {
+                parent: (0..n).collect(),
+                rank: vec![0; n],
+                size: vec![1; n],
+                sets: n,
+            }
+        }
+
+        /// Returns the representative of the set containing `x`, compressing the path to the root on the way.
+        pub fn find(&mut self, x: usize) -> usize {
+            let mut root = x;
+            while self.parent[root] != root {
+                root = self.parent[root];
+            }
+            //Point every element on the path directly at the root
+            let mut u = x;
+            while self.parent[u] != root {
+                let next = self.parent[u];
+                self.parent[u] = root;
+                u = next;
+            }
+            root
+        }
+
+        /// Merges the sets containing `a` and `b`.
+        /// Returns `false` when they were already in the same set.
+        pub fn union(&mut self, a: usize, b: usize) -> bool {
+            let mut a = self.find(a);
+            let mut b = self.find(b);
+            if a == b {
+                return false;
+            }
+            //Attach the shallower tree below the deeper one
+            if self.rank[a] < self.rank[b] {
+                std::mem::swap(&mut a, &mut b);
+            }
+            self.parent[b] = a;
+            self.size[a] += self.size[b];
+            if self.rank[a] == self.rank[b] {
+                self.rank[a] += 1;
+            }
+            self.sets -= 1;
+            true
+        }
+
+        /// Returns `true` when `a` and `b` are in the same set.
+        pub fn connected(&mut self, a: usize, b: usize) -> bool {
+            self.find(a) == self.find(b)
+        }
+
+        /// Returns the number of elements in the set containing `x`.
+        pub fn set_size(&mut self, x: usize) -> usize {
+            let root = self.find(x);
+            self.size[root]
+        }
+
+        /// Returns the number of disjoint sets.
+        pub fn count(&self) -> usize {
+            self.sets
+        }
+    }
+
+    /// Finds the weakly connected components of a directed graph, i.e. the components obtained when the direction of every edge is ignored.
+    /// Every edge is merged into a union-find structure, so the adjacency list does not need to be symmetrised first.
+    ///
+    /// # Arguments
+    ///
+    /// * `adj_list` - A directed graph represented as an adjacency list, as taken by `kosaraju_algorithm`.
+    ///
+    /// # Returns
+    ///
+    /// * `list_of_wcc` - A list of weakly connected components which are internally in sorted order represented as Vec[Vec[]].
+    ///
+    /// # Example
+    /// ```
+    /// let adj_list = vec![
+    ///     vec![1],    // Node 0 has edge to node 1
+    ///     vec![],     // Node 1 has no outgoing edges
+    ///     vec![1],    // Node 2 has edge to node 1
+    ///     vec![4],    // Node 3 has edge to node 4
+    ///     vec![],     // Node 4 has no outgoing edges
+    /// ];
+    ///
+    /// assert_eq!(weakly_connected_components(&adj_list), vec![vec![0, 1, 2], vec![3, 4]]);
+    /// ```
+    pub fn weakly_connected_components(adj_list: &[Vec<usize>]) -> Vec<Vec<usize>> {
+        let mut sets = UnionFind::new(adj_list.len());
+        for (u, neighbors) in adj_list.iter().enumerate() {
+            for &v in neighbors {
+                sets.union(u, v); //Direction is ignored, so u -> v joins both endpoints
+            }
+        }
+
+        //Group the vertices by the representative of their set
+        let mut groups = vec![Vec::new(); adj_list.len()];
+        for u in 0..adj_list.len() {
+            let root = sets.find(u);
+            groups[root].push(u);
+        }
+        let mut list_of_wcc: Vec<Vec<usize>> = groups.into_iter().filter(|g| !g.is_empty()).collect();
+        list_of_wcc.sort(); //Sorting the components like kosaraju_algorithm does
+        list_of_wcc
+    }
+
+    /// Finds the connected components of an undirected graph entered by the user.
+    /// The number of components is tracked with a union-find structure while the edges are entered.
+    ///
+    /// # Input
+    /// * `no_of_vertices` - Input the number of vertices in the graph
+    /// * `no_of_edges` - Input the number of edges in the graph
+    /// * `source` - The source vertex of an edge in the graph
+    /// * `destination` - The destination of an edge in the graph
+    ///
+    /// # Output
+    ///
+    /// Prints how every edge changes the components, then the label and size of every component
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 5
+    ///Please Enter Number of edges in the graph : 2
+    ///Source : 0
+    ///Destination : 1
+    ///Source : 3
+    ///Destination : 4
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///Edge (0, 1) merged two components into one of size 2, 4 components left
+    ///Edge (3, 4) merged two components into one of size 2, 3 components left
+    ///Number of connected components: 3
+    ///Labels: [0, 0, 1, 2, 2]
+    ///Sizes: [2, 1, 2]
+    ///Components: [[0, 1], [2], [3, 4]]
+    /// ```
+    pub fn connected() {
+        //read the number of vertices from the console
+        let mut vertex = String::new();
+        println!("******Connected Components*********");
+        println!("****************************************************");
+        //get the number of vertices
+        print!("Please Enter Number of Vertices : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut vertex)
+            .expect("Enter valid number of vertices");
+        let vertices: usize = vertex.trim().parse().expect("Invalid input");
+        //get number of edges in the graph
+        let mut n_edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut n_edges).expect("Enter Valid Input");
+        let n_edges: i32 = n_edges.trim().parse().expect("Invalid input for edges");
+
+        let mut g = Graph::new(vertices);
+        let mut sets = UnionFind::new(vertices);
+        for _i in 0..n_edges {
+            //intialize source and destination
+            let mut s = String::new();
+            let mut d = String::new();
+            //get the source
+            print!("Source : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut s)
+                .expect("Please Enter Valid Input for .");
+            let s: usize = s.trim().parse().expect("Invalid input for source");
+            //get the destination
+            print!("Destination : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut d)
+                .expect("Please Enter Valid Input for .");
+            let d: usize = d.trim().parse().expect("Invalid input for destination");
+            //add the undirected edge and merge the two sets as the edge streams in
+            g.add_edge(s, d);
+            if sets.connected(s, d) {
+                println!("Edge ({}, {}) is inside an existing component", s, d);
+            } else {
+                sets.union(s, d);
+                println!(
+                    "Edge ({}, {}) merged two components into one of size {}, {} components left",
+                    s,
+                    d,
+                    sets.set_size(s),
+                    sets.count()
+                );
+            }
+        }
+
+        let components = connected_components(&g);
+        println!("******************");
+        println!("Number of connected components: {}", components.count());
+        println!("Labels: {:?}", components.labels);
+        println!("Sizes: {:?}", components.sizes);
+        println!("Components: {:?}", components.members());
+    }
+
+    /// Finds the weakly connected components of a directed graph entered by the user.
+    /// The strongly connected components are printed alongside for comparison.
+    ///
+    /// # Input
+    ///
+    /// * `Number of vertices` - Number of vertices in the graph.
+    /// * `Number of neighbors for each vertex`
+    /// * `Next neighbor for each vertex`
+    ///
+    /// # Output
+    ///
+    /// Prints the weakly and strongly connected components of the graph
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 4
+    /// Please enter the number of neighbors for vertex 0 : 1
+    /// Please enter the next neighbor for vertex 0 : 1
+    /// Please enter the number of neighbors for vertex 1 : 0
+    /// Please enter the number of neighbors for vertex 2 : 1
+    /// Please enter the next neighbor for vertex 2 : 1
+    /// Please enter the number of neighbors for vertex 3 : 0
+    /// ```
+    /// # Sample output
+    /// ```
+    /// The weakly connected components are:
+    /// [[0, 1, 2], [3]]
+    /// The strongly connected components are:
+    /// [[0], [1], [2], [3]]
+    /// ```
+    pub fn weakly_connected() {
+        println!("******Weakly Connected Components*******");
+        println!("******************");
+        let adj_list = add_neighbors(); // Reading the directed adjacency list from the user
+
+        println!("******************");
+        println!("The weakly connected components are:");
+        println!("{:?}", weakly_connected_components(&adj_list));
+        println!("The strongly connected components are:");
+        println!("{:?}", kosaraju_algorithm(&adj_list));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::connected_components::*;
+    use crate::list_of_algorithms::bfs::bfs::Graph;
+
+    #[test]
+    fn test_connected_components() {
+        let mut g = Graph::new(7);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(3, 4);
+        g.add_edge(5, 5);
+
+        let components = connected_components(&g);
+        assert_eq!(components.labels, vec![0, 0, 0, 1, 1, 2, 3]);
+        assert_eq!(components.sizes, vec![3, 2, 1, 1]);
+        assert_eq!(components.count(), 4);
+        assert_eq!(
+            components.members(),
+            vec![vec![0, 1, 2], vec![3, 4], vec![5], vec![6]]
+        );
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let g = Graph::new(0);
+        let components = connected_components(&g);
+        assert_eq!(components.count(), 0);
+        assert!(components.labels.is_empty());
+    }
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.count(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2)); //already in the same set
+
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.set_size(2), 4);
+        assert_eq!(sets.count(), 3);
+    }
+
+    #[test]
+    fn test_weakly_connected_components() {
+        //0 -> 1 <- 2 is one weak component although no vertex reaches both others
+        let adj_list = vec![vec![1], vec![], vec![1], vec![4], vec![], vec![5]];
+
+        let result = weakly_connected_components(&adj_list);
+        assert_eq!(result, vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn test_weakly_contains_strongly_connected() {
+        let adj_list = vec![vec![1], vec![2], vec![0, 3], vec![4], vec![3], vec![], vec![7], vec![5, 6]];
+
+        assert_eq!(
+            weakly_connected_components(&adj_list),
+            vec![vec![0, 1, 2, 3, 4], vec![5, 6, 7]]
+        );
+    }
+}
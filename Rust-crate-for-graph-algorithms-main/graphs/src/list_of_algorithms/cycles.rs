@@ -0,0 +1,376 @@
+pub mod cycles {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::kosaraju::kosaraju::{add_neighbors, kosaraju_algorithm};
+    use std::io::{stdin, stdout, Write};
+
+    //Marks a vertex without a parent in the Depth-First Search
+    const NONE: usize = usize::MAX;
+
+    /// Checks whether a graph contains a cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `adj_list` - The graph represented as an adjacency list. An undirected graph lists every edge at both endpoints,
+    ///   as `bfs::Graph::add_edge` does.
+    /// * `directed` - Whether the edges are directed.
+    ///
+    /// # Returns
+    ///
+    /// * `true` when the graph has a cycle. For undirected graphs a self-loop or two parallel edges count as a cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let adj_list = vec![vec![1], vec![2], vec![]];
+    /// assert!(!has_cycle(&adj_list, true));
+    ///
+    /// //The same path with every edge listed at both endpoints is still acyclic
+    /// let adj_list = vec![vec![1], vec![0, 2], vec![1]];
+    /// assert!(!has_cycle(&adj_list, false));
+    /// ```
+    pub fn has_cycle(adj_list: &[Vec<usize>], directed: bool) -> bool {
+        find_cycle(adj_list, directed).is_some()
+    }
+
+    /// Finds one cycle of a graph with Depth-First Search.
+    /// In a directed graph every edge back to a vertex on the current DFS path closes a cycle.
+    /// In an undirected graph the same holds for every edge except the one leading back to the parent.
+    ///
+    /// # Arguments
+    ///
+    /// * `adj_list` - The graph represented as an adjacency list. An undirected graph lists every edge at both endpoints.
+    /// * `directed` - Whether the edges are directed.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(cycle)` - The vertices of a cycle in order, where the last vertex is joined back to the first.
+    /// * `None` - When the graph is acyclic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let adj_list = vec![
+    ///     vec![1],    // Node 0 has edge to node 1
+    ///     vec![2],    // Node 1 has edge to node 2
+    ///     vec![3],    // Node 2 has edge to node 3
+    ///     vec![1],    // Node 3 has edge to node 1
+    /// ];
+    ///
+    /// assert_eq!(find_cycle(&adj_list, true), Some(vec![1, 2, 3]));
+    /// ```
+    pub fn find_cycle(adj_list: &[Vec<usize>], directed: bool) -> Option<Vec<usize>> {
+        let mut state = vec![0u8; adj_list.len()]; //0 unvisited, 1 on the DFS path, 2 finished
+        let mut path = Vec::new();
+        for u in 0..adj_list.len() {
+            if state[u] == 0 {
+                if let Some(cycle) = search(u, NONE, adj_list, directed, &mut state, &mut path) {
+                    return Some(cycle);
+                }
+            }
+        }
+        return None;
+
+        fn search(
+            u: usize,
+            parent: usize,
+            adj_list: &[Vec<usize>],
+            directed: bool,
+            state: &mut [u8],
+            path: &mut Vec<usize>,
+        ) -> Option<Vec<usize>> {
+            state[u] = 1;
+            path.push(u);
+            let mut skipped_parent = false;
+            for &v in &adj_list[u] {
+                if !directed && v == parent && !skipped_parent {
+                    skipped_parent = true; //The tree edge itself, a second parallel edge would close a cycle
+                    continue;
+                }
+                if state[v] == 1 {
+                    //v is on the current path, so the path from v to u and the edge u -> v form a cycle
+                    let start = path.iter().rposition(|&x| x == v).unwrap_or(0);
+                    return Some(path[start..].to_vec());
+                }
+                if state[v] == 0 {
+                    if let Some(cycle) = search(v, u, adj_list, directed, state, path) {
+                        return Some(cycle);
+                    }
+                }
+            }
+            state[u] = 2;
+            path.pop();
+            None
+        }
+    }
+
+    //State of the search for the cycles through the start vertex s in Johnson's algorithm
+    struct Johnson<'a> {
+        adj_list: Vec<Vec<usize>>,  //the strongly connected component of s
+        blocked: Vec<bool>,         //vertices that cannot lead back to s right now
+        blocked_by: Vec<Vec<usize>>, //vertices to unblock when a vertex gets unblocked
+        path: Vec<usize>,
+        limit: usize,
+        cycles: &'a mut Vec<Vec<usize>>,
+    }
+
+    impl Johnson<'_> {
+        fn unblock(&mut self, u: usize) {
+            self.blocked[u] = false;
+            while let Some(w) = self.blocked_by[u].pop() {
+                if self.blocked[w] {
+                    self.unblock(w);
+                }
+            }
+        }
+
+        //Extends the path with v and returns true when some cycle through s was found or cut off below v
+        fn circuit(&mut self, v: usize, s: usize) -> bool {
+            let mut found = false;
+            self.path.push(v);
+            self.blocked[v] = true;
+            for i in 0..self.adj_list[v].len() {
+                let w = self.adj_list[v][i];
+                if w == s {
+                    if self.path.len() <= self.limit {
+                        self.cycles.push(self.path.clone());
+                    }
+                    found = true;
+                } else if !self.blocked[w] {
+                    if self.path.len() < self.limit {
+                        found |= self.circuit(w, s);
+                    } else {
+                        //Cut off by the length limit, so v must not stay blocked as if no cycle could pass through it
+                        found = true;
+                    }
+                }
+            }
+            if found {
+                self.unblock(v);
+            } else {
+                for i in 0..self.adj_list[v].len() {
+                    let w = self.adj_list[v][i];
+                    if !self.blocked_by[w].contains(&v) {
+                        self.blocked_by[w].push(v);
+                    }
+                }
+            }
+            self.path.pop();
+            found
+        }
+    }
+
+    /// Johnson's algorithm enumerates all elementary cycles of a directed graph in O((V + E) * (C + 1)) time for C cycles.
+    /// For every start vertex s in increasing order it searches the strongly connected component of s within the vertices
+    /// not smaller than s, and blocks vertices that cannot currently lead back to s so that no dead end is explored twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `adj_list` - A directed graph represented as an adjacency list, as taken by `kosaraju_algorithm`. Parallel edges are merged.
+    /// * `max_len` - When given, only cycles with at most this many vertices are returned.
+    ///
+    /// # Returns
+    ///
+    /// * `cycles` - Every elementary cycle once, starting at its smallest vertex, in sorted order. The last vertex of each cycle
+    ///   is joined back to the first and a self-loop is a cycle of one vertex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let adj_list = vec![
+    ///     vec![1, 2], // Node 0 has edges to nodes 1 and 2
+    ///     vec![0, 2], // Node 1 has edges to nodes 0 and 2
+    ///     vec![0],    // Node 2 has edge to node 0
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     elementary_cycles(&adj_list, None),
+    ///     vec![vec![0, 1], vec![0, 1, 2], vec![0, 2]]
+    /// );
+    /// assert_eq!(elementary_cycles(&adj_list, Some(2)), vec![vec![0, 1], vec![0, 2]]);
+    /// ```
+    pub fn elementary_cycles(adj_list: &[Vec<usize>], max_len: Option<usize>) -> Vec<Vec<usize>> {
+        let n = adj_list.len();
+        let mut cycles = Vec::new();
+        for s in 0..n {
+            //The subgraph induced by the vertices from s on, without parallel edges
+            let sub: Vec<Vec<usize>> = (0..n)
+                .map(|u| {
+                    let mut neighbors: Vec<usize> = if u < s {
+                        Vec::new()
+                    } else {
+                        adj_list[u].iter().copied().filter(|&v| v >= s).collect()
+                    };
+                    neighbors.sort();
+                    neighbors.dedup();
+                    neighbors
+                })
+                .collect();
+
+            //Cycles through s stay inside its strongly connected component
+            let mut in_component = vec![false; n];
+            for scc in kosaraju_algorithm(&sub) {
+                if scc.binary_search(&s).is_ok() {
+                    for v in scc {
+                        in_component[v] = true;
+                    }
+                }
+            }
+            let component = sub
+                .into_iter()
+                .enumerate()
+                .map(|(u, neighbors)| {
+                    if in_component[u] {
+                        neighbors.into_iter().filter(|&v| in_component[v]).collect()
+                    } else {
+                        Vec::new()
+                    }
+                })
+                .collect();
+
+            let mut search = Johnson {
+                adj_list: component,
+                blocked: vec![false; n],
+                blocked_by: vec![Vec::new(); n],
+                path: Vec::new(),
+                limit: max_len.unwrap_or(n),
+                cycles: &mut cycles,
+            };
+            search.circuit(s, s);
+        }
+        cycles.sort();
+        cycles
+    }
+
+    /// Finds the cycles of a directed graph entered by the user.
+    ///
+    /// # Input
+    ///
+    /// * `Number of vertices` - Number of vertices in the graph.
+    /// * `Number of neighbors for each vertex`
+    /// * `Next neighbor for each vertex`
+    /// * `Maximum cycle length` - The longest cycle to list, 0 for no limit.
+    ///
+    /// # Output
+    ///
+    /// Prints one cycle of the directed graph, whether the graph has a cycle with directions ignored, then all elementary cycles
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 3
+    /// Please enter the number of neighbors for vertex 0 : 2
+    /// Please enter the next neighbor for vertex 0 : 1
+    /// Please enter the next neighbor for vertex 0 : 2
+    /// Please enter the number of neighbors for vertex 1 : 1
+    /// Please enter the next neighbor for vertex 1 : 2
+    /// Please enter the number of neighbors for vertex 2 : 0
+    /// Please Enter Maximum Cycle Length (0 for no limit) : 0
+    /// ```
+    /// # Sample output
+    /// ```
+    /// The directed graph has no cycle
+    /// Ignoring directions, the graph has a cycle
+    /// The elementary cycles are:
+    /// []
+    /// ```
+    pub fn cycles() {
+        println!("******Cycle Detection*******");
+        println!("******************");
+        let adj_list = add_neighbors(); // Reading the directed adjacency list from the user
+
+        // Read the length limit of the listed cycles
+        let mut buffer = String::new();
+        print!("Please Enter Maximum Cycle Length (0 for no limit) : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut buffer)
+            .expect("Please Enter Valid number for the length.");
+        let max_len: usize = buffer.trim().parse().expect("Invalid input for length");
+        let max_len = if max_len == 0 { None } else { Some(max_len) };
+
+        //List every edge at both endpoints to look at the graph as undirected
+        let mut undirected = vec![Vec::new(); adj_list.len()];
+        for (u, neighbors) in adj_list.iter().enumerate() {
+            for &v in neighbors {
+                undirected[u].push(v);
+                undirected[v].push(u);
+            }
+        }
+
+        println!("******************");
+        match find_cycle(&adj_list, true) {
+            Some(cycle) => println!("A directed cycle is : {:?}", cycle),
+            None => println!("The directed graph has no cycle"),
+        }
+        if has_cycle(&undirected, false) {
+            println!("Ignoring directions, the graph has a cycle");
+        } else {
+            println!("Ignoring directions, the graph has no cycle");
+        }
+        println!("The elementary cycles are:");
+        println!("{:?}", elementary_cycles(&adj_list, max_len));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cycles::*;
+
+    #[test]
+    fn test_find_cycle_directed() {
+        //A diamond has no directed cycle until the last edge closes one
+        let mut adj_list = vec![vec![1, 2], vec![3], vec![3], vec![]];
+        assert!(!has_cycle(&adj_list, true));
+        assert_eq!(find_cycle(&adj_list, true), None);
+        adj_list[3].push(0);
+        assert_eq!(find_cycle(&adj_list, true), Some(vec![0, 1, 3]));
+
+        let self_loop = vec![vec![], vec![1]];
+        assert_eq!(find_cycle(&self_loop, true), Some(vec![1]));
+    }
+
+    #[test]
+    fn test_find_cycle_undirected() {
+        //A tree listed at both endpoints is acyclic
+        let tree = vec![vec![1, 2], vec![0], vec![0, 3], vec![2]];
+        assert!(!has_cycle(&tree, false));
+        assert!(has_cycle(&tree, true)); //As a directed graph every edge is a 2-cycle
+
+        let square = vec![vec![1, 3], vec![0, 2], vec![1, 3], vec![2, 0]];
+        assert_eq!(find_cycle(&square, false), Some(vec![0, 1, 2, 3]));
+
+        //Two parallel edges between 0 and 1
+        let parallel = vec![vec![1, 1], vec![0, 0]];
+        assert_eq!(find_cycle(&parallel, false), Some(vec![0, 1]));
+    }
+
+    #[test]
+    fn test_elementary_cycles() {
+        //Two SCCs, a self-loop and a parallel edge
+        let adj_list = vec![
+            vec![1],
+            vec![2, 2],
+            vec![0, 1, 3],
+            vec![4],
+            vec![3, 4],
+        ];
+        assert_eq!(
+            elementary_cycles(&adj_list, None),
+            vec![vec![0, 1, 2], vec![1, 2], vec![3, 4], vec![4]]
+        );
+        assert_eq!(
+            elementary_cycles(&adj_list, Some(2)),
+            vec![vec![1, 2], vec![3, 4], vec![4]]
+        );
+        assert!(elementary_cycles(&[vec![1], vec![]], None).is_empty());
+    }
+
+    #[test]
+    fn test_complete_digraph() {
+        //A complete directed graph on 4 vertices has 6 + 8 + 6 = 20 elementary cycles
+        let adj_list: Vec<Vec<usize>> = (0..4)
+            .map(|u| (0..4).filter(|&v| v != u).collect())
+            .collect();
+        assert_eq!(elementary_cycles(&adj_list, None).len(), 20);
+        assert_eq!(elementary_cycles(&adj_list, Some(3)).len(), 14);
+    }
+}
@@ -1,7 +1,7 @@
 pub mod dijkstras {
     use std::{
         cmp::Ordering, // Importing Ordering to make node structure comparision based on distances
-        collections::{HashSet}, // Importing BinaryHeap and HashSet to get priority Node and to mark visited Nodes
+        collections::{HashSet, VecDeque}, // Importing HashSet to mark visited Nodes and VecDeque to trace shortest paths
         io::{stdin, stdout, Write}, // Importing input/output library for reading user input and for printing output
     };
 
@@ -75,6 +75,46 @@ pub mod dijkstras {
             self.adj_list[v].push(Node { vertex: u, dist: w }); // Add Vertex `u` as adjacent vertex of Vertex `v`
         }
 
+        /// Returns the number of vertices in the graph.
+        pub fn vertex_count(&self) -> usize {
+            self.vertices
+        }
+
+        /// Returns the neighbors of vertex `u` together with the weight of the connecting edge.
+        pub fn neighbors(&self, u: usize) -> Vec<(usize, i32)> {
+            self.adj_list[u].iter().map(|n| (n.vertex, n.dist)).collect()
+        }
+
+        /// Returns every undirected edge of the graph once as `(u, v, w)` with `u <= v`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// let mut g = Graph::new(3);
+        /// g.add_edge(1, 0, 10);
+        /// g.add_edge(1, 2, 20);
+        ///
+        /// assert_eq!(g.edge_list(), vec![(0, 1, 10), (1, 2, 20)]);
+        /// ```
+        pub fn edge_list(&self) -> Vec<(usize, usize, i32)> {
+            let mut edges = Vec::new();
+            for (u, adj) in self.adj_list.iter().enumerate() {
+                let mut self_loops = 0;
+                for node in adj {
+                    if node.vertex > u {
+                        edges.push((u, node.vertex, node.dist)); // Each edge is stored at both ends, keep the copy at the smaller end
+                    } else if node.vertex == u {
+                        // A self-loop is stored twice in the same list, keep every second copy
+                        self_loops += 1;
+                        if self_loops % 2 == 0 {
+                            edges.push((u, u, node.dist));
+                        }
+                    }
+                }
+            }
+            edges
+        }
+
         /// performs Dijkstra's algorithm on a weighted graph to find the shortest path from a source vertex to every vertex in the graph.
         ///
         /// # Arguments
@@ -139,6 +179,56 @@ pub mod dijkstras {
             // Return the distances from source to every other vertex
             dist
         }
+
+        /// Finds a shortest path between two vertices using the distances computed by `dijkstra`.
+        ///
+        /// # Arguments
+        ///
+        /// * `src` - The index of the source vertex.
+        /// * `dst` - The index of the destination vertex.
+        ///
+        /// # Returns
+        ///
+        /// The vertices of a shortest path from `src` to `dst`, or `None` when `dst` cannot be reached.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// let mut g = Graph::new(4);
+        /// g.add_edge(0, 1, 1);
+        /// g.add_edge(0, 2, 4);
+        /// g.add_edge(1, 2, 2);
+        /// g.add_edge(2, 3, 1);
+        ///
+        /// assert_eq!(g.shortest_path(0, 3), Some(vec![0, 1, 2, 3]));
+        /// ```
+        pub fn shortest_path(&self, src: usize, dst: usize) -> Option<Vec<usize>> {
+            let dist = self.dijkstra(src); // Shortest distances from the source
+            if dist[dst] == i32::MAX {
+                return None; // The destination is unreachable
+            }
+            let mut parent = vec![usize::MAX; self.vertices]; // Previous vertex on the path found to every vertex
+            let mut queue = VecDeque::from([src]);
+            parent[src] = src;
+            // Following only the edges that keep the distance tight visits every vertex along a shortest path
+            while let Some(u) = queue.pop_front() {
+                for Node { vertex: v, dist: w } in &self.adj_list[u] {
+                    if parent[*v] == usize::MAX && dist[u] != i32::MAX && dist[u] + *w == dist[*v] {
+                        parent[*v] = u;
+                        queue.push_back(*v);
+                    }
+                }
+            }
+            // Walking back from the destination to the source
+            let mut path = vec![dst];
+            let mut v = dst;
+            while v != src {
+                v = parent[v];
+                path.push(v);
+            }
+            path.reverse();
+            Some(path)
+        }
     }
 
     /// Performs Dijkstra's algorithm on a given directed graph represented as an adjacency list.
@@ -230,6 +320,20 @@ pub mod dijkstras {
             .parse()
             .expect("Invalid input for number of edges");
 
+        // Reading all edges source, destination and weight from the user
+        let g = add_edges(vertices, edges);
+
+        // Calling Dijkstra's algorithm to find the shortest path from th e Source
+        let dist = g.dijkstra(source);
+        println!("******************");
+        // Looping and printing the distances from Source to respective vertices
+        for (v, d) in dist.iter().enumerate() {
+            println!("Distance from vertex {} to vertex {} is {}", source, v, d);
+        }
+    }
+
+    /// To return the edges entered by the user as a weighted undirected graph
+    pub fn add_edges(vertices: usize, edges: i32) -> Graph {
         // Creating a new graph with the number of vertices entered by the user
         let mut g = Graph::new(vertices);
 
@@ -280,13 +384,8 @@ pub mod dijkstras {
             cnt = cnt + 1;
         }
 
-        // Calling Dijkstra's algorithm to find the shortest path from th e Source
-        let dist = g.dijkstra(source);
-        println!("******************");
-        // Looping and printing the distances from Source to respective vertices
-        for (v, d) in dist.iter().enumerate() {
-            println!("Distance from vertex {} to vertex {} is {}", source, v, d);
-        }
+        // Returning the graph with all the edges entered
+        g
     }
 }
 // stdin, stdout and Write trait from std::io module
@@ -334,4 +433,16 @@ mod tests {
         assert_eq!(dist, vec![0, 10, 5, i32::max_value(), i32::max_value()]);
     }
 
+    #[test]
+    fn test_shortest_path() {
+        let mut g = Graph::new(5);
+        g.add_edge(0, 1, 10);
+        g.add_edge(0, 2, 5);
+        g.add_edge(2, 1, 3);
+        g.add_edge(1, 3, 1);
+        assert_eq!(g.shortest_path(0, 3), Some(vec![0, 2, 1, 3]));
+        assert_eq!(g.shortest_path(3, 3), Some(vec![3]));
+        assert_eq!(g.shortest_path(0, 4), None);
+    }
+
 }
@@ -0,0 +1,381 @@
+pub mod distance_metrics {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::bfs::bfs;
+    use crate::list_of_algorithms::dijkstras::dijkstras::{self, add_edges};
+    use std::{
+        cmp::Reverse,
+        io::{stdin, stdout, Write},
+    };
+
+    /// Distance-based measures of a whole graph. Infinite distances, which occur in disconnected graphs, are `i32::MAX`.
+    pub struct DistanceMetrics {
+        /// The largest distance from every vertex to any other vertex.
+        pub eccentricity: Vec<i32>,
+        /// The largest eccentricity.
+        pub diameter: i32,
+        /// The smallest eccentricity.
+        pub radius: i32,
+        /// The vertices whose eccentricity equals the radius, in sorted order.
+        pub center: Vec<usize>,
+        /// The vertices whose eccentricity equals the diameter, in sorted order.
+        pub periphery: Vec<usize>,
+    }
+
+    /// How `diameter` and `weighted_diameter` compute the diameter.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum DiameterMode {
+        /// A shortest-path search from every vertex, always exact.
+        Exact,
+        /// Two searches: from vertex 0 to the farthest vertex a, and from a. Gives a lower bound that is often exact.
+        DoubleSweep,
+        /// The iFUB algorithm: exact, but usually needs only a few searches. Starting from a central vertex u, the
+        /// eccentricities of the vertices farthest from u are computed until they prove that no closer pair can be farther apart.
+        IFub,
+    }
+
+    //Unweighted distances from src, with i32::MAX for unreachable vertices
+    fn hop_distances(g: &bfs::Graph, src: usize) -> Vec<i32> {
+        let n = g.edges.len();
+        let mut parent = vec![usize::MAX; n];
+        let mut depth = vec![usize::MAX; n];
+        g.bfs_tree(src, &mut parent, &mut depth);
+        depth
+            .into_iter()
+            .map(|d| if d == usize::MAX { i32::MAX } else { d as i32 })
+            .collect()
+    }
+
+    //Returns the first vertex at the largest distance
+    fn farthest(dist: &[i32]) -> usize {
+        let max = dist.iter().copied().max().unwrap_or(0);
+        dist.iter().position(|&d| d == max).unwrap_or(0)
+    }
+
+    //Computes all metrics from the distances of every vertex
+    fn metrics_from(n: usize, distances: impl Fn(usize) -> Vec<i32>) -> DistanceMetrics {
+        let eccentricity: Vec<i32> = (0..n)
+            .map(|u| distances(u).into_iter().max().unwrap_or(0))
+            .collect();
+        let diameter = eccentricity.iter().copied().max().unwrap_or(0);
+        let radius = eccentricity.iter().copied().min().unwrap_or(0);
+        DistanceMetrics {
+            center: (0..n).filter(|&u| eccentricity[u] == radius).collect(),
+            periphery: (0..n).filter(|&u| eccentricity[u] == diameter).collect(),
+            eccentricity,
+            diameter,
+            radius,
+        }
+    }
+
+    //Computes the diameter in the given mode from single-source distances
+    fn diameter_from(n: usize, mode: DiameterMode, distances: impl Fn(usize) -> Vec<i32>) -> i32 {
+        if n == 0 {
+            return 0;
+        }
+        if mode == DiameterMode::Exact {
+            return metrics_from(n, distances).diameter;
+        }
+        let from_first = distances(0);
+        if from_first.contains(&i32::MAX) {
+            return i32::MAX; //The graph is disconnected
+        }
+        let a = farthest(&from_first);
+        let from_a = distances(a);
+        let b = farthest(&from_a);
+        let mut lower = from_a[b]; //The eccentricity of a
+        if mode == DiameterMode::DoubleSweep {
+            return lower;
+        }
+
+        //Start iFUB from the vertex that is closest to both ends of the double sweep
+        let from_b = distances(b);
+        let u = (0..n)
+            .min_by_key(|&v| from_a[v].max(from_b[v]))
+            .unwrap_or(0);
+        let from_u = distances(u);
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by_key(|&v| Reverse(from_u[v]));
+
+        let mut i = 0;
+        while i < n {
+            let level = from_u[order[i]];
+            //Two vertices at distance at most level from u are at most 2 * level apart
+            if lower >= level.saturating_mul(2) {
+                break;
+            }
+            while i < n && from_u[order[i]] == level {
+                let ecc = distances(order[i]).into_iter().max().unwrap_or(0);
+                lower = lower.max(ecc);
+                i += 1;
+            }
+        }
+        lower
+    }
+
+    /// Computes the eccentricity of every vertex of an unweighted graph, and from them the diameter, radius, center and periphery.
+    /// Runs a Breadth-First Search from every vertex, taking O(V * (V + E)) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Distances count edges.
+    ///
+    /// # Returns
+    ///
+    /// * `DistanceMetrics` - The eccentricities and the measures derived from them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //A path 0-1-2-3 with an extra leaf 4 on vertex 1
+    /// let mut g = Graph::new(5);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(1, 2);
+    /// g.add_edge(2, 3);
+    /// g.add_edge(1, 4);
+    ///
+    /// let metrics = distance_metrics(&g);
+    /// assert_eq!(metrics.eccentricity, vec![3, 2, 2, 3, 3]);
+    /// assert_eq!((metrics.diameter, metrics.radius), (3, 2));
+    /// assert_eq!(metrics.center, vec![1, 2]);
+    /// assert_eq!(metrics.periphery, vec![0, 3, 4]);
+    /// ```
+    pub fn distance_metrics(g: &bfs::Graph) -> DistanceMetrics {
+        metrics_from(g.edges.len(), |u| hop_distances(g, u))
+    }
+
+    /// Computes the eccentricity of every vertex of a weighted graph, and from them the diameter, radius, center and periphery.
+    /// Runs `dijkstra` from every vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`, with non-negative weights.
+    ///
+    /// # Returns
+    ///
+    /// * `DistanceMetrics` - The eccentricities and the measures derived from them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(3);
+    /// g.add_edge(0, 1, 4);
+    /// g.add_edge(1, 2, 1);
+    ///
+    /// let metrics = weighted_distance_metrics(&g);
+    /// assert_eq!(metrics.eccentricity, vec![5, 4, 5]);
+    /// assert_eq!(metrics.center, vec![1]);
+    /// ```
+    pub fn weighted_distance_metrics(g: &dijkstras::Graph) -> DistanceMetrics {
+        metrics_from(g.vertex_count(), |u| g.dijkstra(u))
+    }
+
+    /// Computes the diameter of an unweighted graph, exactly or with fewer Breadth-First Searches (see `DiameterMode`).
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`.
+    /// * `mode` - How the diameter is computed.
+    ///
+    /// # Returns
+    ///
+    /// * The diameter, a lower bound of it for `DiameterMode::DoubleSweep`, or `i32::MAX` when the graph is disconnected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(1, 2);
+    /// g.add_edge(1, 3);
+    ///
+    /// assert_eq!(diameter(&g, DiameterMode::Exact), 2);
+    /// assert_eq!(diameter(&g, DiameterMode::IFub), 2);
+    /// ```
+    pub fn diameter(g: &bfs::Graph, mode: DiameterMode) -> i32 {
+        diameter_from(g.edges.len(), mode, |u| hop_distances(g, u))
+    }
+
+    /// Computes the diameter of a weighted graph, exactly or with fewer runs of `dijkstra` (see `DiameterMode`).
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`, with non-negative weights.
+    /// * `mode` - How the diameter is computed.
+    ///
+    /// # Returns
+    ///
+    /// * The diameter, a lower bound of it for `DiameterMode::DoubleSweep`, or `i32::MAX` when the graph is disconnected.
+    pub fn weighted_diameter(g: &dijkstras::Graph, mode: DiameterMode) -> i32 {
+        diameter_from(g.vertex_count(), mode, |u| g.dijkstra(u))
+    }
+
+    /// Computes distance metrics of a weighted undirected graph entered by the user,
+    /// both with the edge weights and counting edges.
+    ///
+    /// # Inputs
+    ///
+    /// * `vertices` - Total number of vertices in the graph.
+    ///
+    /// * `edges` - Total Number of edges in the graph.
+    ///
+    /// * `Source(s) Destination(d) Weight(w) ` - Source, Destination and Weight for each edge in the graph.
+    ///
+    /// # Output
+    ///
+    /// Prints the eccentricities, diameter, radius, center and periphery, and the diameter in every mode.
+    ///
+    /// # Sample Input
+    /// ```
+    /// Please Enter Number of Vertices in the Graph : 3
+    /// Please Enter Number of edges in the graph : 2
+    /// Please Enter Edge 1 values
+    /// Source : 0
+    /// Destination : 1
+    /// Weight(>0) : 4
+    /// Please Enter Edge 2 values
+    /// Source : 1
+    /// Destination : 2
+    /// Weight(>0) : 1
+    /// ```
+    /// # Sample Output
+    /// ```
+    /// Weighted : eccentricity [5, 4, 5], diameter 5, radius 4, center [1], periphery [0, 2]
+    /// Weighted diameter : exact 5, double sweep 5, iFUB 5
+    /// Unweighted : eccentricity [2, 1, 2], diameter 2, radius 1, center [1], periphery [0, 2]
+    /// Unweighted diameter : exact 2, double sweep 2, iFUB 2
+    /// ```
+    pub fn distances() {
+        // Create an empty string to store the number of vertices
+        let mut ve = String::new();
+
+        // Printing the introduction message
+        println!("******Distance Metrics*******");
+        println!("******************");
+
+        // Prompting user to input number of vertices in the Graph
+        print!("Please Enter Number of Vertices in the Graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut ve)
+            .expect("Please Enter Valid number for vertices.");
+        let vertices: usize = ve
+            .trim()
+            .parse()
+            .expect("Invalid input for number of vertices");
+
+        // Prompting user to input the number of edges
+        let mut edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut edges)
+            .expect("Please Enter Valid Input for number of Edges.");
+        let edges: i32 = edges
+            .trim()
+            .parse()
+            .expect("Invalid input for number of edges");
+
+        // Reading all edges source, destination and weight from the user
+        let g = add_edges(vertices, edges);
+        // The unweighted metrics count the edges of the same graph
+        let mut unweighted = bfs::Graph::new(vertices);
+        for (u, v, _) in g.edge_list() {
+            unweighted.add_edge(u, v);
+        }
+
+        println!("******************");
+        for (name, metrics, diameters) in [
+            (
+                "Weighted",
+                weighted_distance_metrics(&g),
+                [DiameterMode::Exact, DiameterMode::DoubleSweep, DiameterMode::IFub]
+                    .map(|mode| weighted_diameter(&g, mode)),
+            ),
+            (
+                "Unweighted",
+                distance_metrics(&unweighted),
+                [DiameterMode::Exact, DiameterMode::DoubleSweep, DiameterMode::IFub]
+                    .map(|mode| diameter(&unweighted, mode)),
+            ),
+        ] {
+            println!(
+                "{} : eccentricity {:?}, diameter {}, radius {}, center {:?}, periphery {:?}",
+                name,
+                metrics.eccentricity,
+                metrics.diameter,
+                metrics.radius,
+                metrics.center,
+                metrics.periphery
+            );
+            println!(
+                "{} diameter : exact {}, double sweep {}, iFUB {}",
+                name, diameters[0], diameters[1], diameters[2]
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::distance_metrics::*;
+    use crate::list_of_algorithms::bfs::bfs;
+    use crate::list_of_algorithms::dijkstras::dijkstras;
+
+    #[test]
+    fn test_unweighted_metrics() {
+        //A 6-cycle: every vertex has eccentricity 3
+        let mut g = bfs::Graph::new(6);
+        for u in 0..6 {
+            g.add_edge(u, (u + 1) % 6);
+        }
+        let metrics = distance_metrics(&g);
+        assert_eq!(metrics.eccentricity, vec![3; 6]);
+        assert_eq!(metrics.center, metrics.periphery);
+
+        //Disconnected graphs have infinite eccentricities
+        let mut g = bfs::Graph::new(3);
+        g.add_edge(0, 1);
+        let metrics = distance_metrics(&g);
+        assert_eq!(metrics.diameter, i32::MAX);
+        assert_eq!(diameter(&g, DiameterMode::IFub), i32::MAX);
+        assert_eq!(distance_metrics(&bfs::Graph::new(0)).center, Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_diameter_modes() {
+        //A long path with a triangle in the middle, where a double sweep from vertex 0 is exact
+        let mut g = bfs::Graph::new(8);
+        for u in 0..7 {
+            g.add_edge(u, u + 1);
+        }
+        g.add_edge(2, 4);
+        for mode in [DiameterMode::Exact, DiameterMode::DoubleSweep, DiameterMode::IFub] {
+            assert_eq!(diameter(&g, mode), 6);
+        }
+
+        //From vertex 0 the double sweep ends at vertex 1, which misses the pendant vertex 2 at distance 3 from vertex 3
+        let mut g = bfs::Graph::new(5);
+        g.add_edge(0, 3);
+        g.add_edge(0, 4);
+        g.add_edge(1, 3);
+        g.add_edge(1, 4);
+        g.add_edge(2, 4);
+        assert_eq!(diameter(&g, DiameterMode::Exact), 3);
+        assert_eq!(diameter(&g, DiameterMode::DoubleSweep), 2);
+        assert_eq!(diameter(&g, DiameterMode::IFub), 3);
+    }
+
+    #[test]
+    fn test_weighted_metrics() {
+        let mut g = dijkstras::Graph::new(4);
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(0, 3, 10);
+        let metrics = weighted_distance_metrics(&g);
+        assert_eq!(metrics.eccentricity, vec![3, 2, 2, 3]);
+        assert_eq!((metrics.diameter, metrics.radius), (3, 2));
+        assert_eq!(weighted_diameter(&g, DiameterMode::IFub), 3);
+        assert_eq!(weighted_diameter(&g, DiameterMode::DoubleSweep), 3);
+    }
+}
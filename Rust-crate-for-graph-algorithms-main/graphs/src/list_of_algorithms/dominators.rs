@@ -0,0 +1,275 @@
+pub mod dominators {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::dfs::dfs::Graph;
+    use crate::list_of_algorithms::kosaraju::kosaraju::add_neighbors;
+    use std::io::{stdin, stdout, Write};
+
+    //Vertices reachable from the entry in postorder of a Depth-First Search, keeping the recursion on a stack
+    fn postorder(g: &Graph, entry: usize) -> Vec<usize> {
+        let mut visited = vec![false; g.edges.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![(entry, 0)];
+        visited[entry] = true;
+        while let Some(&mut (u, ref mut i)) = stack.last_mut() {
+            if *i == g.edges[u].len() {
+                order.push(u);
+                stack.pop();
+                continue;
+            }
+            let v = g.edges[u][*i];
+            *i += 1;
+            if !visited[v] {
+                visited[v] = true;
+                stack.push((v, 0));
+            }
+        }
+        order
+    }
+
+    /// Computes the immediate dominator of every vertex of a flow graph with the iterative algorithm of
+    /// Cooper, Harvey and Kennedy. A vertex `d` dominates `v` when every path from the entry to `v` passes through `d`;
+    /// the immediate dominator is the closest strict dominator. The dominators of the predecessors are intersected
+    /// by walking up the current dominator tree by postorder number, visiting the vertices in reverse postorder
+    /// until nothing changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A directed graph built with `dfs::Graph::add_edge`.
+    /// * `entry` - The vertex every path starts from.
+    ///
+    /// # Returns
+    ///
+    /// * `idom` - The immediate dominator of every vertex. The entry is its own immediate dominator,
+    ///   and vertices unreachable from the entry have `usize::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //A diamond 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3 followed by 3 -> 4
+    /// let mut g = Graph::new(5);
+    /// for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)] {
+    ///     g.add_edge(u, v);
+    /// }
+    /// assert_eq!(immediate_dominators(&g, 0), vec![0, 0, 0, 0, 3]);
+    /// ```
+    pub fn immediate_dominators(g: &Graph, entry: usize) -> Vec<usize> {
+        let n = g.edges.len();
+        let order = postorder(g, entry);
+        let mut number = vec![usize::MAX; n];
+        for (i, &v) in order.iter().enumerate() {
+            number[v] = i;
+        }
+        let mut preds = vec![Vec::new(); n];
+        for (u, adj) in g.edges.iter().enumerate() {
+            if number[u] != usize::MAX {
+                for &v in adj {
+                    preds[v].push(u);
+                }
+            }
+        }
+
+        let mut idom = vec![usize::MAX; n];
+        idom[entry] = entry;
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &v in order.iter().rev().skip(1) {
+                let mut new_idom = usize::MAX;
+                for &p in &preds[v] {
+                    if idom[p] == usize::MAX {
+                        continue; //Not processed yet
+                    }
+                    if new_idom == usize::MAX {
+                        new_idom = p;
+                        continue;
+                    }
+                    //Walk both fingers up to their common dominator, the lower postorder number moving first
+                    let (mut a, mut b) = (p, new_idom);
+                    while a != b {
+                        while number[a] < number[b] {
+                            a = idom[a];
+                        }
+                        while number[b] < number[a] {
+                            b = idom[b];
+                        }
+                    }
+                    new_idom = a;
+                }
+                if idom[v] != new_idom {
+                    idom[v] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+        idom
+    }
+
+    /// Builds the dominator tree from the immediate dominators: the children of every vertex in increasing order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let idom = vec![0, 0, 0, 0, 3];
+    /// assert_eq!(dominator_tree(&idom), vec![vec![1, 2, 3], vec![], vec![], vec![4], vec![]]);
+    /// ```
+    pub fn dominator_tree(idom: &[usize]) -> Vec<Vec<usize>> {
+        let mut children = vec![Vec::new(); idom.len()];
+        for (v, &d) in idom.iter().enumerate() {
+            if d != usize::MAX && d != v {
+                children[d].push(v);
+            }
+        }
+        children
+    }
+
+    /// Computes the dominance frontier of every vertex: the vertices where its dominance ends, which it does not
+    /// strictly dominate but which have a predecessor it dominates. These are where a definition in the vertex
+    /// meets other definitions, as used to place phi functions in SSA form.
+    /// From every predecessor of a vertex, the tree is climbed up to the immediate dominator of the vertex,
+    /// adding the vertex to the frontier of every vertex passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A directed graph built with `dfs::Graph::add_edge`.
+    /// * `idom` - The immediate dominators of `g` from `immediate_dominators`.
+    ///
+    /// # Returns
+    ///
+    /// * `frontiers` - The dominance frontier of every vertex in increasing order, empty for unreachable vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(5);
+    /// for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)] {
+    ///     g.add_edge(u, v);
+    /// }
+    /// let idom = immediate_dominators(&g, 0);
+    /// assert_eq!(dominance_frontiers(&g, &idom), vec![vec![], vec![3], vec![3], vec![], vec![]]);
+    /// ```
+    pub fn dominance_frontiers(g: &Graph, idom: &[usize]) -> Vec<Vec<usize>> {
+        let mut frontiers = vec![Vec::new(); g.edges.len()];
+        for (p, adj) in g.edges.iter().enumerate() {
+            if idom[p] == usize::MAX {
+                continue;
+            }
+            for &v in adj {
+                //The entry has no immediate dominator above it, so the climb stops after the entry itself
+                let stop = if idom[v] == v { usize::MAX } else { idom[v] };
+                let mut runner = p;
+                while runner != stop {
+                    frontiers[runner].push(v);
+                    if idom[runner] == runner {
+                        break;
+                    }
+                    runner = idom[runner];
+                }
+            }
+        }
+        for frontier in frontiers.iter_mut() {
+            frontier.sort_unstable();
+            frontier.dedup();
+        }
+        frontiers
+    }
+
+    /// Computes the dominators of a flow graph entered by the user.
+    ///
+    /// # Input
+    ///
+    /// * `Number of vertices` - Number of vertices in the graph.
+    /// * `Number of neighbors for each vertex`
+    /// * `Next neighbor for each vertex`
+    /// * `Entry vertex` - The vertex every path starts from.
+    ///
+    /// # Output
+    ///
+    /// Prints the immediate dominators, the dominator tree and the dominance frontiers
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 4
+    /// Please enter the number of neighbors for vertex 0 : 2
+    /// Please enter the next neighbor for vertex 0 : 1
+    /// Please enter the next neighbor for vertex 0 : 2
+    /// Please enter the number of neighbors for vertex 1 : 1
+    /// Please enter the next neighbor for vertex 1 : 3
+    /// Please enter the number of neighbors for vertex 2 : 1
+    /// Please enter the next neighbor for vertex 2 : 3
+    /// Please enter the number of neighbors for vertex 3 : 0
+    /// Please Enter Entry Vertex : 0
+    /// ```
+    /// # Sample output
+    /// ```
+    /// Immediate dominators : [0, 0, 0, 0]
+    /// Dominator tree : [[1, 2, 3], [], [], []]
+    /// Dominance frontiers : [[], [3], [3], []]
+    /// ```
+    pub fn dominators() {
+        println!("******Dominators*******");
+        println!("******************");
+        let adj_list = add_neighbors(); // Reading the directed adjacency list from the user
+        let g = Graph {
+            vertices: adj_list.len(),
+            edges: adj_list,
+        };
+
+        // Prompting user to input the entry vertex
+        let mut entry = String::new();
+        print!("Please Enter Entry Vertex : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut entry)
+            .expect("Please Enter Valid Input for entry vertex.");
+        let entry: usize = entry.trim().parse().expect("Invalid input for entry vertex");
+
+        let idom = immediate_dominators(&g, entry);
+        println!("******************");
+        println!("Immediate dominators : {:?}", idom);
+        println!("Dominator tree : {:?}", dominator_tree(&idom));
+        println!("Dominance frontiers : {:?}", dominance_frontiers(&g, &idom));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dominators::*;
+    use crate::list_of_algorithms::dfs::dfs::Graph;
+
+    #[test]
+    fn test_loops() {
+        //0 -> 1 -> 2 -> 3 -> 4 with a loop 3 -> 1 and a branch 1 -> 3 skipping 2
+        let mut g = Graph::new(5);
+        for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 1), (1, 3), (3, 4)] {
+            g.add_edge(u, v);
+        }
+        let idom = immediate_dominators(&g, 0);
+        assert_eq!(idom, vec![0, 0, 1, 1, 3]);
+        let frontiers = dominance_frontiers(&g, &idom);
+        assert_eq!(frontiers, vec![vec![], vec![1], vec![3], vec![1], vec![]]);
+
+        //A loop back to the entry puts the entry in its own frontier
+        let mut g = Graph::new(2);
+        g.add_edge(0, 1);
+        g.add_edge(1, 0);
+        let idom = immediate_dominators(&g, 0);
+        assert_eq!(dominance_frontiers(&g, &idom), vec![vec![0], vec![0]]);
+    }
+
+    #[test]
+    fn test_irreducible_and_unreachable() {
+        //An irreducible loop 3 <-> 4 entered from 1 and from 2, and vertex 5 unreachable from the entry
+        let mut g = Graph::new(6);
+        for (u, v) in [(0, 1), (0, 2), (1, 4), (2, 3), (3, 4), (4, 3), (5, 0)] {
+            g.add_edge(u, v);
+        }
+        let idom = immediate_dominators(&g, 0);
+        assert_eq!(idom, vec![0, 0, 0, 0, 0, usize::MAX]);
+        assert_eq!(dominator_tree(&idom)[0], vec![1, 2, 3, 4]);
+        let frontiers = dominance_frontiers(&g, &idom);
+        assert_eq!(frontiers[1], vec![4]);
+        assert_eq!(frontiers[3], vec![4]);
+        assert_eq!(frontiers[4], vec![3]);
+        assert!(frontiers[5].is_empty());
+    }
+}
@@ -0,0 +1,561 @@
+pub mod euler {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::bfs::bfs;
+    use crate::list_of_algorithms::blossom::blossom::max_weight_matching;
+    use crate::list_of_algorithms::connected_components::connected_components::{
+        connected_components, weakly_connected_components,
+    };
+    use crate::list_of_algorithms::dijkstras::dijkstras::{self, add_edges};
+    use crate::list_of_algorithms::kosaraju::kosaraju::add_neighbors;
+    use std::io::{stdin, stdout, Write};
+
+    /// Whether a graph has a walk that uses every edge exactly once.
+    #[derive(Debug, PartialEq)]
+    pub enum Eulerian {
+        /// There is a closed walk using every edge once. A graph without edges has an empty circuit.
+        Circuit,
+        /// There is a walk from `start` to `end` using every edge once, but no closed one.
+        Path { start: usize, end: usize },
+        /// No walk uses every edge exactly once.
+        Neither,
+    }
+
+    /// A closed walk covering every edge of a weighted undirected graph at least once.
+    pub struct PostmanTour {
+        /// The vertices of the walk in order. The first and last vertex are the same.
+        pub route: Vec<usize>,
+        /// The total weight of the walk, counting every edge as often as it is traversed.
+        pub total_weight: i32,
+    }
+
+    //Returns every undirected edge once, with self-loops listed twice in the same adjacency list kept once
+    fn undirected_edges(g: &bfs::Graph) -> Vec<(usize, usize)> {
+        let mut edges = Vec::new();
+        for (u, neighbors) in g.edges.iter().enumerate() {
+            let mut self_loops = 0;
+            for &v in neighbors {
+                if v > u {
+                    edges.push((u, v));
+                } else if v == u {
+                    self_loops += 1;
+                    if self_loops % 2 == 0 {
+                        edges.push((u, u));
+                    }
+                }
+            }
+        }
+        edges
+    }
+
+    //Checks that all edges lie in one connected component, ignoring isolated vertices
+    fn edges_connected(g: &bfs::Graph) -> bool {
+        let labels = connected_components(g).labels;
+        let mut with_edges = (0..g.edges.len()).filter(|&u| !g.edges[u].is_empty());
+        match with_edges.next() {
+            Some(first) => with_edges.all(|u| labels[u] == labels[first]),
+            None => true,
+        }
+    }
+
+    //Hierholzer's algorithm on an undirected multigraph given as an edge list
+    fn hierholzer_undirected(n: usize, edges: &[(usize, usize)], start: usize) -> Vec<usize> {
+        let mut incident = vec![Vec::new(); n]; //edges touching every vertex
+        for (i, &(u, v)) in edges.iter().enumerate() {
+            incident[u].push(i);
+            if u != v {
+                incident[v].push(i);
+            }
+        }
+        let mut used = vec![false; edges.len()];
+        let mut next = vec![0; n]; //first incident edge of every vertex that may still be unused
+        let mut stack = vec![start];
+        let mut trail = Vec::new();
+        while let Some(&u) = stack.last() {
+            while next[u] < incident[u].len() && used[incident[u][next[u]]] {
+                next[u] += 1;
+            }
+            if next[u] == incident[u].len() {
+                //Every edge of u is used, so u is the next vertex of the trail counted from the end
+                trail.push(u);
+                stack.pop();
+            } else {
+                let e = incident[u][next[u]];
+                used[e] = true;
+                let (a, b) = edges[e];
+                stack.push(if a == u { b } else { a });
+            }
+        }
+        trail.reverse();
+        trail
+    }
+
+    /// Checks the conditions for an Eulerian path or circuit in a directed graph.
+    /// A circuit needs equal in-degree and out-degree at every vertex. A path needs one vertex with one more outgoing edge,
+    /// one with one more incoming edge, and balanced degrees elsewhere. In both cases all edges must be weakly connected.
+    ///
+    /// # Arguments
+    ///
+    /// * `adj_list` - A directed graph represented as an adjacency list, as taken by `kosaraju_algorithm`.
+    ///
+    /// # Returns
+    ///
+    /// * `Eulerian` - Whether the graph has an Eulerian circuit, an Eulerian path with its endpoints, or neither.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let adj_list = vec![vec![1], vec![2], vec![0, 3], vec![]];
+    /// assert_eq!(classify_directed(&adj_list), Eulerian::Path { start: 2, end: 3 });
+    /// ```
+    pub fn classify_directed(adj_list: &[Vec<usize>]) -> Eulerian {
+        let n = adj_list.len();
+        let mut balance = vec![0i64; n]; //out-degree minus in-degree
+        for (u, neighbors) in adj_list.iter().enumerate() {
+            balance[u] += neighbors.len() as i64;
+            for &v in neighbors {
+                balance[v] -= 1;
+            }
+        }
+
+        //All edges must lie in one weakly connected component
+        let with_edges = weakly_connected_components(adj_list)
+            .into_iter()
+            .filter(|c| c.iter().any(|&u| !adj_list[u].is_empty()))
+            .count();
+        if with_edges > 1 {
+            return Eulerian::Neither;
+        }
+
+        let starts: Vec<usize> = (0..n).filter(|&u| balance[u] == 1).collect();
+        let ends: Vec<usize> = (0..n).filter(|&u| balance[u] == -1).collect();
+        let unbalanced = (0..n).filter(|&u| balance[u] != 0).count();
+        match (starts.len(), ends.len(), unbalanced) {
+            (0, 0, 0) => Eulerian::Circuit,
+            (1, 1, 2) => Eulerian::Path {
+                start: starts[0],
+                end: ends[0],
+            },
+            _ => Eulerian::Neither,
+        }
+    }
+
+    /// Checks the conditions for an Eulerian path or circuit in an undirected graph.
+    /// A circuit needs every vertex to have even degree, and a path exactly two vertices of odd degree, which are its endpoints.
+    /// In both cases all edges must be connected.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`.
+    ///
+    /// # Returns
+    ///
+    /// * `Eulerian` - Whether the graph has an Eulerian circuit, an Eulerian path with its endpoints, or neither.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(3);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(1, 2);
+    /// assert_eq!(classify_undirected(&g), Eulerian::Path { start: 0, end: 2 });
+    ///
+    /// g.add_edge(2, 0);
+    /// assert_eq!(classify_undirected(&g), Eulerian::Circuit);
+    /// ```
+    pub fn classify_undirected(g: &bfs::Graph) -> Eulerian {
+        //A self-loop is listed twice in its adjacency list and adds two to the degree
+        let odd: Vec<usize> = (0..g.edges.len())
+            .filter(|&u| g.edges[u].len() % 2 == 1)
+            .collect();
+
+        if !edges_connected(g) {
+            return Eulerian::Neither;
+        }
+        match odd.len() {
+            0 => Eulerian::Circuit,
+            2 => Eulerian::Path {
+                start: odd[0],
+                end: odd[1],
+            },
+            _ => Eulerian::Neither,
+        }
+    }
+
+    /// Builds an Eulerian path or circuit of a directed graph with Hierholzer's algorithm in O(V + E) time.
+    /// The walk follows unused edges until it gets stuck, and splices in the detours found while backtracking.
+    ///
+    /// # Arguments
+    ///
+    /// * `adj_list` - A directed graph represented as an adjacency list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(trail)` - The vertices of the walk in order, starting at the smallest vertex with an edge for a circuit.
+    ///   The trail is empty when the graph has no edges.
+    /// * `None` - When the graph is not Eulerian.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let adj_list = vec![vec![1], vec![2], vec![0, 3], vec![]];
+    /// assert_eq!(euler_directed(&adj_list), Some(vec![2, 0, 1, 2, 3]));
+    /// ```
+    pub fn euler_directed(adj_list: &[Vec<usize>]) -> Option<Vec<usize>> {
+        let start = match classify_directed(adj_list) {
+            Eulerian::Neither => return None,
+            Eulerian::Path { start, .. } => start,
+            Eulerian::Circuit => match (0..adj_list.len()).find(|&u| !adj_list[u].is_empty()) {
+                Some(u) => u,
+                None => return Some(Vec::new()),
+            },
+        };
+
+        let mut next = vec![0; adj_list.len()]; //next unused outgoing edge of every vertex
+        let mut stack = vec![start];
+        let mut trail = Vec::new();
+        while let Some(&u) = stack.last() {
+            if next[u] < adj_list[u].len() {
+                stack.push(adj_list[u][next[u]]);
+                next[u] += 1;
+            } else {
+                trail.push(u);
+                stack.pop();
+            }
+        }
+        trail.reverse();
+        Some(trail)
+    }
+
+    /// Builds an Eulerian path or circuit of an undirected graph with Hierholzer's algorithm in O(V + E) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(trail)` - The vertices of the walk in order, starting at the smaller odd vertex for a path and at the smallest
+    ///   vertex with an edge for a circuit. The trail is empty when the graph has no edges.
+    /// * `None` - When the graph is not Eulerian.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(3);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(1, 2);
+    /// g.add_edge(2, 0);
+    /// assert_eq!(euler_undirected(&g), Some(vec![0, 1, 2, 0]));
+    /// ```
+    pub fn euler_undirected(g: &bfs::Graph) -> Option<Vec<usize>> {
+        let edges = undirected_edges(g);
+        let start = match classify_undirected(g) {
+            Eulerian::Neither => return None,
+            Eulerian::Path { start, .. } => start,
+            Eulerian::Circuit => match edges.first() {
+                Some(&(u, _)) => u,
+                None => return Some(Vec::new()),
+            },
+        };
+        Some(hierholzer_undirected(g.edges.len(), &edges, start))
+    }
+
+    /// Solves the Chinese postman (route inspection) problem: the shortest closed walk that traverses every edge at least once.
+    /// The vertices of odd degree are paired by a minimum-weight perfect matching on their shortest-path distances,
+    /// the shortest paths between the pairs are added as duplicate edges, and the resulting Eulerian multigraph is walked
+    /// with Hierholzer's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`, with non-negative weights.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(PostmanTour)` - The closed walk and its total weight. The route is empty when the graph has no edges.
+    /// * `None` - When the edges are not all connected, so that no closed walk can cover them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(3);
+    /// g.add_edge(0, 1, 2);
+    /// g.add_edge(1, 2, 3);
+    ///
+    /// //The path has to be walked there and back
+    /// let tour = chinese_postman(&g).unwrap();
+    /// assert_eq!(tour.route, vec![0, 1, 2, 1, 0]);
+    /// assert_eq!(tour.total_weight, 10);
+    /// ```
+    pub fn chinese_postman(g: &dijkstras::Graph) -> Option<PostmanTour> {
+        let n = g.vertex_count();
+        let weighted = g.edge_list();
+        let mut unweighted = bfs::Graph::new(n);
+        for &(u, v, _) in &weighted {
+            unweighted.add_edge(u, v);
+        }
+        if !edges_connected(&unweighted) {
+            return None;
+        }
+        let start = match weighted.first() {
+            Some(&(u, _, _)) => u,
+            None => {
+                return Some(PostmanTour {
+                    route: Vec::new(),
+                    total_weight: 0,
+                })
+            }
+        };
+
+        //Pair the odd vertices so that the total length of the shortest paths between the pairs is minimal
+        let odd: Vec<usize> = (0..n)
+            .filter(|&u| unweighted.edges[u].len() % 2 == 1)
+            .collect();
+        let dist: Vec<Vec<i32>> = odd.iter().map(|&u| g.dijkstra(u)).collect();
+        let longest = (0..odd.len())
+            .flat_map(|i| odd.iter().map(move |&v| (i, v)))
+            .map(|(i, v)| dist[i][v])
+            .max()
+            .unwrap_or(0);
+        let mut pairing = dijkstras::Graph::new(odd.len());
+        for (i, row) in dist.iter().enumerate() {
+            for j in i + 1..odd.len() {
+                //A maximum-weight perfect matching on longest + 1 - distance has the minimum total distance
+                pairing.add_edge(i, j, longest + 1 - row[odd[j]]);
+            }
+        }
+
+        let mut edges: Vec<(usize, usize)> = weighted.iter().map(|&(u, v, _)| (u, v)).collect();
+        let mut total_weight: i32 = weighted.iter().map(|&(_, _, w)| w).sum();
+        for (i, j) in max_weight_matching(&pairing, true).pairs {
+            let path = g.shortest_path(odd[i], odd[j]).unwrap_or_default();
+            for step in path.windows(2) {
+                edges.push((step[0], step[1])); //The path is walked a second time
+            }
+            total_weight += dist[i][odd[j]];
+        }
+
+        Some(PostmanTour {
+            route: hierholzer_undirected(n, &edges, start),
+            total_weight,
+        })
+    }
+
+    /// Finds Eulerian paths and circuits of a directed graph entered by the user,
+    /// both following the edge directions and ignoring them.
+    ///
+    /// # Input
+    ///
+    /// * `Number of vertices` - Number of vertices in the graph.
+    /// * `Number of neighbors for each vertex`
+    /// * `Next neighbor for each vertex`
+    ///
+    /// # Output
+    ///
+    /// Prints whether the graph is Eulerian and the Eulerian walk when there is one
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 4
+    /// Please enter the number of neighbors for vertex 0 : 1
+    /// Please enter the next neighbor for vertex 0 : 1
+    /// Please enter the number of neighbors for vertex 1 : 1
+    /// Please enter the next neighbor for vertex 1 : 2
+    /// Please enter the number of neighbors for vertex 2 : 2
+    /// Please enter the next neighbor for vertex 2 : 0
+    /// Please enter the next neighbor for vertex 2 : 3
+    /// Please enter the number of neighbors for vertex 3 : 0
+    /// ```
+    /// # Sample output
+    /// ```
+    /// Directed : Path { start: 2, end: 3 }
+    /// Eulerian walk : [2, 0, 1, 2, 3]
+    /// Undirected : Path { start: 2, end: 3 }
+    /// Eulerian walk : [2, 0, 1, 2, 3]
+    /// ```
+    pub fn euler() {
+        println!("******Eulerian Path*******");
+        println!("******************");
+        let adj_list = add_neighbors(); // Reading the directed adjacency list from the user
+
+        //The same edges with their directions ignored
+        let mut undirected = bfs::Graph::new(adj_list.len());
+        for (u, neighbors) in adj_list.iter().enumerate() {
+            for &v in neighbors {
+                undirected.add_edge(u, v);
+            }
+        }
+
+        println!("******************");
+        println!("Directed : {:?}", classify_directed(&adj_list));
+        if let Some(trail) = euler_directed(&adj_list) {
+            println!("Eulerian walk : {:?}", trail);
+        }
+        println!("Undirected : {:?}", classify_undirected(&undirected));
+        if let Some(trail) = euler_undirected(&undirected) {
+            println!("Eulerian walk : {:?}", trail);
+        }
+    }
+
+    /// Finds the shortest closed walk covering every edge of a weighted undirected graph entered by the user.
+    ///
+    /// # Inputs
+    ///
+    /// * `vertices` - Total number of vertices in the graph.
+    ///
+    /// * `edges` - Total Number of edges in the graph.
+    ///
+    /// * `Source(s) Destination(d) Weight(w) ` - Source, Destination and Weight for each edge in the graph.
+    ///
+    /// # Output
+    ///
+    /// Prints the route and its total weight
+    ///
+    /// # Sample Input
+    /// ```
+    /// Please Enter Number of Vertices in the Graph : 3
+    /// Please Enter Number of edges in the graph : 2
+    /// Please Enter Edge 1 values
+    /// Source : 0
+    /// Destination : 1
+    /// Weight(>0) : 2
+    /// Please Enter Edge 2 values
+    /// Source : 1
+    /// Destination : 2
+    /// Weight(>0) : 3
+    /// ```
+    /// # Sample Output
+    /// ```
+    /// Route : [0, 1, 2, 1, 0]
+    /// Total weight : 10
+    /// ```
+    pub fn postman() {
+        // Create an empty string to store the number of vertices
+        let mut ve = String::new();
+
+        // Printing the introduction message
+        println!("******Chinese Postman*******");
+        println!("******************");
+
+        // Prompting user to input number of vertices in the Graph
+        print!("Please Enter Number of Vertices in the Graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut ve)
+            .expect("Please Enter Valid number for vertices.");
+        let vertices: usize = ve
+            .trim()
+            .parse()
+            .expect("Invalid input for number of vertices");
+
+        // Prompting user to input the number of edges
+        let mut edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut edges)
+            .expect("Please Enter Valid Input for number of Edges.");
+        let edges: i32 = edges
+            .trim()
+            .parse()
+            .expect("Invalid input for number of edges");
+
+        // Reading all edges source, destination and weight from the user
+        let g = add_edges(vertices, edges);
+
+        println!("******************");
+        match chinese_postman(&g) {
+            Some(tour) => {
+                println!("Route : {:?}", tour.route);
+                println!("Total weight : {}", tour.total_weight);
+            }
+            None => println!("The edges are not connected, no route covers them all"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::euler::*;
+    use crate::list_of_algorithms::bfs::bfs;
+    use crate::list_of_algorithms::dijkstras::dijkstras;
+
+    //Checks that the walk uses every edge of the undirected edge list exactly once
+    fn check_trail(edges: &[(usize, usize)], trail: &[usize]) {
+        let mut remaining: Vec<(usize, usize)> = edges.iter().map(|&(u, v)| (u.min(v), u.max(v))).collect();
+        for step in trail.windows(2) {
+            let edge = (step[0].min(step[1]), step[0].max(step[1]));
+            let i = remaining.iter().position(|&e| e == edge).expect("edge used too often");
+            remaining.swap_remove(i);
+        }
+        assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn test_directed() {
+        //Two cycles sharing vertex 0
+        let adj_list = vec![vec![1, 3], vec![2], vec![0], vec![4], vec![0]];
+        assert_eq!(classify_directed(&adj_list), Eulerian::Circuit);
+        assert_eq!(euler_directed(&adj_list), Some(vec![0, 1, 2, 0, 3, 4, 0]));
+
+        //Balanced degrees in two separate cycles
+        let split = vec![vec![1], vec![0], vec![3], vec![2]];
+        assert_eq!(classify_directed(&split), Eulerian::Neither);
+        assert_eq!(euler_directed(&split), None);
+
+        let star = vec![vec![1, 2, 3], vec![], vec![], vec![]];
+        assert_eq!(classify_directed(&star), Eulerian::Neither);
+        assert_eq!(euler_directed(&vec![vec![]; 3]), Some(vec![]));
+    }
+
+    #[test]
+    fn test_undirected() {
+        //A square with a roof and one diagonal has exactly two odd vertices
+        let edges = [(0, 1), (1, 2), (2, 3), (3, 0), (2, 4), (3, 4), (1, 3)];
+        let mut g = bfs::Graph::new(5);
+        for &(u, v) in &edges {
+            g.add_edge(u, v);
+        }
+        assert_eq!(classify_undirected(&g), Eulerian::Path { start: 1, end: 2 });
+        let trail = euler_undirected(&g).unwrap();
+        assert_eq!((trail[0], trail[trail.len() - 1]), (1, 2));
+        check_trail(&edges, &trail);
+
+        //Four odd vertices
+        let mut star = bfs::Graph::new(4);
+        star.add_edge(0, 1);
+        star.add_edge(0, 2);
+        star.add_edge(0, 3);
+        assert_eq!(classify_undirected(&star), Eulerian::Neither);
+        assert_eq!(euler_undirected(&star), None);
+    }
+
+    #[test]
+    fn test_chinese_postman() {
+        //A square with a heavy diagonal
+        let mut g = dijkstras::Graph::new(4);
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 0, 1);
+        g.add_edge(0, 2, 5);
+        let tour = chinese_postman(&g).unwrap();
+        assert_eq!(tour.total_weight, 11);
+        assert_eq!(tour.route.first(), tour.route.last());
+        //The odd vertices 0 and 2 are joined again along the shortest path 0-1-2
+        check_trail(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (0, 1), (1, 2)], &tour.route);
+
+        //An Eulerian graph needs no extra edges
+        let mut triangle = dijkstras::Graph::new(3);
+        triangle.add_edge(0, 1, 4);
+        triangle.add_edge(1, 2, 5);
+        triangle.add_edge(2, 0, 6);
+        let tour = chinese_postman(&triangle).unwrap();
+        assert_eq!(tour.route, vec![0, 1, 2, 0]);
+        assert_eq!(tour.total_weight, 15);
+
+        let mut split = dijkstras::Graph::new(4);
+        split.add_edge(0, 1, 1);
+        split.add_edge(2, 3, 1);
+        assert!(chinese_postman(&split).is_none());
+    }
+}
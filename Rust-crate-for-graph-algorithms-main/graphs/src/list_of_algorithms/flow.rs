@@ -0,0 +1,501 @@
+pub mod flow {
+    //Importng necessary libraries
+    use std::{
+        collections::VecDeque,
+        io::{stdin, stdout, Write},
+    };
+
+    /// A directed edge of a flow network.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct FlowEdge {
+        /// The tail of the edge.
+        pub from: usize,
+        /// The head of the edge.
+        pub to: usize,
+        /// The maximum amount of flow the edge can carry.
+        pub capacity: i32,
+        /// The cost per unit of flow, used by the min-cost flow solvers. It may be negative.
+        pub cost: i32,
+    }
+
+    /// A flow network stored as a list of directed edges with capacities and costs.
+    pub struct FlowNetwork {
+        /// The edges of the network, indexed in the order they were added.
+        pub edges: Vec<FlowEdge>,
+        /// The total number of vertices in the network.
+        pub vertices: usize,
+    }
+
+    impl FlowNetwork {
+        /// Constructs a new flow network with the specified number of vertices and no edges.
+        pub fn new(vertices: usize) -> Self {
+            FlowNetwork {
+                edges: Vec::new(),
+                vertices,
+            }
+        }
+
+        /// Adds a directed edge from `u` to `v` with the given capacity and returns its index.
+        /// The index can be used to look up the flow on the edge in `MaxFlow::edge_flows`.
+        pub fn add_edge(&mut self, u: usize, v: usize, capacity: i32) -> usize {
+            self.add_edge_with_cost(u, v, capacity, 0)
+        }
+
+        /// Adds a directed edge from `u` to `v` with the given capacity and cost per unit of flow, and returns its index.
+        pub fn add_edge_with_cost(&mut self, u: usize, v: usize, capacity: i32, cost: i32) -> usize {
+            self.edges.push(FlowEdge {
+                from: u,
+                to: v,
+                capacity,
+                cost,
+            });
+            self.edges.len() - 1
+        }
+    }
+
+    /// The result of a maximum flow computation.
+    pub struct MaxFlow {
+        /// The value of the maximum flow from the source to the sink.
+        pub value: i32,
+        /// The flow on every edge, indexed like `FlowNetwork::edges`.
+        pub edge_flows: Vec<i32>,
+        /// The vertices on the source side of a minimum cut, in sorted order.
+        pub source_side: Vec<usize>,
+        /// The vertices on the sink side of a minimum cut, in sorted order.
+        pub sink_side: Vec<usize>,
+        /// The indices of the edges crossing the minimum cut from the source side to the sink side.
+        /// Their capacities add up to `value`.
+        pub cut_edges: Vec<usize>,
+    }
+
+    //Residual graph where edge i of the network becomes arc 2 * i and its reverse arc 2 * i + 1
+    struct Residual {
+        adj: Vec<Vec<usize>>, //arcs leaving every vertex
+        to: Vec<usize>,       //head of every arc
+        cap: Vec<i32>,        //remaining capacity of every arc
+    }
+
+    impl Residual {
+        fn new(net: &FlowNetwork) -> Self {
+            let mut adj = vec![Vec::new(); net.vertices];
+            let mut to = Vec::new();
+            let mut cap = Vec::new();
+            for (i, e) in net.edges.iter().enumerate() {
+                adj[e.from].push(2 * i);
+                to.push(e.to);
+                cap.push(e.capacity);
+                adj[e.to].push(2 * i + 1);
+                to.push(e.from);
+                cap.push(0);
+            }
+            Residual { adj, to, cap }
+        }
+
+        //Breadth-First Search over arcs with remaining capacity, returns the level of every vertex
+        fn levels(&self, s: usize) -> Vec<usize> {
+            let mut level = vec![usize::MAX; self.adj.len()];
+            let mut queue = VecDeque::new();
+            level[s] = 0;
+            queue.push_back(s);
+            while let Some(u) = queue.pop_front() {
+                for &a in &self.adj[u] {
+                    let v = self.to[a];
+                    if self.cap[a] > 0 && level[v] == usize::MAX {
+                        level[v] = level[u] + 1;
+                        queue.push_back(v);
+                    }
+                }
+            }
+            level
+        }
+
+        //Reads the flow on every edge and the minimum cut off the final residual graph
+        fn into_max_flow(self, net: &FlowNetwork, s: usize, value: i32) -> MaxFlow {
+            let edge_flows = net
+                .edges
+                .iter()
+                .enumerate()
+                .map(|(i, e)| e.capacity - self.cap[2 * i])
+                .collect();
+            //The vertices still reachable from the source form the source side of a minimum cut
+            let level = self.levels(s);
+            let (source_side, sink_side) = (0..net.vertices).partition(|&u| level[u] != usize::MAX);
+            let cut_edges = (0..net.edges.len())
+                .filter(|&i| {
+                    level[net.edges[i].from] != usize::MAX && level[net.edges[i].to] == usize::MAX
+                })
+                .collect();
+            MaxFlow {
+                value,
+                edge_flows,
+                source_side,
+                sink_side,
+                cut_edges,
+            }
+        }
+    }
+
+    /// The Edmonds-Karp algorithm computes a maximum flow by repeatedly augmenting along a shortest path found with Breadth-First Search.
+    /// It runs in O(V * E^2) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `net` - The flow network. Capacities must be non-negative.
+    /// * `s` - The source vertex.
+    /// * `t` - The sink vertex.
+    ///
+    /// # Returns
+    ///
+    /// * `MaxFlow` - The flow value, the flow on every edge and a minimum cut.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut net = FlowNetwork::new(4);
+    /// net.add_edge(0, 1, 3);
+    /// net.add_edge(0, 2, 2);
+    /// net.add_edge(1, 2, 5);
+    /// net.add_edge(1, 3, 2);
+    /// net.add_edge(2, 3, 3);
+    ///
+    /// let result = edmonds_karp(&net, 0, 3);
+    /// assert_eq!(result.value, 5);
+    /// assert_eq!(result.source_side, vec![0]);
+    /// ```
+    pub fn edmonds_karp(net: &FlowNetwork, s: usize, t: usize) -> MaxFlow {
+        if s == t {
+            panic!("Source and sink must be different vertices");
+        }
+        let mut res = Residual::new(net);
+        let mut value = 0;
+        loop {
+            //Breadth-First Search for a shortest augmenting path, remembering the arc used to reach every vertex
+            let mut parent_arc = vec![usize::MAX; net.vertices];
+            let mut visited = vec![false; net.vertices];
+            let mut queue = VecDeque::new();
+            visited[s] = true;
+            queue.push_back(s);
+            while let Some(u) = queue.pop_front() {
+                if u == t {
+                    break;
+                }
+                for &a in &res.adj[u] {
+                    let v = res.to[a];
+                    if res.cap[a] > 0 && !visited[v] {
+                        visited[v] = true;
+                        parent_arc[v] = a;
+                        queue.push_back(v);
+                    }
+                }
+            }
+            if !visited[t] {
+                break; //No augmenting path is left
+            }
+
+            //The bottleneck is the smallest remaining capacity on the path
+            let mut bottleneck = i32::MAX;
+            let mut v = t;
+            while v != s {
+                let a = parent_arc[v];
+                bottleneck = bottleneck.min(res.cap[a]);
+                v = res.to[a ^ 1];
+            }
+            //Push the bottleneck along the path
+            let mut v = t;
+            while v != s {
+                let a = parent_arc[v];
+                res.cap[a] -= bottleneck;
+                res.cap[a ^ 1] += bottleneck;
+                v = res.to[a ^ 1];
+            }
+            value += bottleneck;
+        }
+        res.into_max_flow(net, s, value)
+    }
+
+    /// Dinic's algorithm computes a maximum flow by building a level graph with Breadth-First Search
+    /// and saturating it with a blocking flow found by Depth-First Search. It runs in O(V^2 * E) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `net` - The flow network. Capacities must be non-negative.
+    /// * `s` - The source vertex.
+    /// * `t` - The sink vertex.
+    ///
+    /// # Returns
+    ///
+    /// * `MaxFlow` - The flow value, the flow on every edge and a minimum cut.
+    pub fn dinic(net: &FlowNetwork, s: usize, t: usize) -> MaxFlow {
+        if s == t {
+            panic!("Source and sink must be different vertices");
+        }
+        let mut res = Residual::new(net);
+        let mut value = 0;
+        loop {
+            let level = res.levels(s);
+            if level[t] == usize::MAX {
+                break; //The sink is no longer reachable
+            }
+            let mut next = vec![0; net.vertices]; //next[u] is the first arc of u that may still carry flow
+            loop {
+                let pushed = augment(&mut res, &level, &mut next, s, t, i32::MAX);
+                if pushed == 0 {
+                    break;
+                }
+                value += pushed;
+            }
+        }
+
+        //Depth-First Search along the level graph, returns the amount of flow pushed from u
+        fn augment(
+            res: &mut Residual,
+            level: &[usize],
+            next: &mut [usize],
+            u: usize,
+            t: usize,
+            limit: i32,
+        ) -> i32 {
+            if u == t {
+                return limit;
+            }
+            while next[u] < res.adj[u].len() {
+                let a = res.adj[u][next[u]];
+                let v = res.to[a];
+                if res.cap[a] > 0 && level[v] == level[u] + 1 {
+                    let pushed = augment(res, level, next, v, t, limit.min(res.cap[a]));
+                    if pushed > 0 {
+                        res.cap[a] -= pushed;
+                        res.cap[a ^ 1] += pushed;
+                        return pushed;
+                    }
+                }
+                next[u] += 1; //The arc is saturated or leads to a dead end
+            }
+            0
+        }
+
+        res.into_max_flow(net, s, value)
+    }
+
+    /// Computes the maximum flow and a minimum cut of a flow network entered by the user.
+    ///
+    /// # Input
+    /// * `no_of_vertices` - Input the number of vertices in the network
+    /// * `no_of_edges` - Input the number of edges in the network
+    /// * `source` - The source vertex of an edge in the network
+    /// * `destination` - The destination of an edge in the network
+    /// * `capacity` - The capacity of the corresponding edge
+    /// * `Source vertex` and `Sink vertex` - The vertices between which the flow is computed
+    ///
+    /// # Output
+    ///
+    /// Prints the maximum flow found by Edmonds-Karp and Dinic, the flow on every edge and a minimum cut
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 4
+    ///Please Enter Number of edges in the network : 5
+    ///Source : 0
+    ///Destination : 1
+    ///Capacity : 3
+    ///Source : 0
+    ///Destination : 2
+    ///Capacity : 2
+    ///Source : 1
+    ///Destination : 2
+    ///Capacity : 5
+    ///Source : 1
+    ///Destination : 3
+    ///Capacity : 2
+    ///Source : 2
+    ///Destination : 3
+    ///Capacity : 3
+    ///Enter Source Vertex : 0
+    ///Enter Sink Vertex : 3
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///Maximum flow (Edmonds-Karp) : 5
+    ///Maximum flow (Dinic) : 5
+    ///Flow on edge 0 -> 1 is 3 of 3
+    ///Flow on edge 0 -> 2 is 2 of 2
+    ///Flow on edge 1 -> 2 is 1 of 5
+    ///Flow on edge 1 -> 3 is 2 of 2
+    ///Flow on edge 2 -> 3 is 3 of 3
+    ///Minimum cut : [0] | [1, 2, 3]
+    ///Cut edges : [(0, 1), (0, 2)]
+    /// ```
+    pub fn max_flow() {
+        //read the number of vertices from the console
+        let mut vertex = String::new();
+        println!("********Maximum Flow***********");
+        println!("****************************************************");
+        //get the number of vertices
+        print!("Please Enter Number of Vertices : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut vertex)
+            .expect("Enter valid number of vertices");
+        let vertices: usize = vertex.trim().parse().expect("Invalid input");
+        //get number of edges in the network
+        let mut n_edges = String::new();
+        print!("Please Enter Number of edges in the network : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut n_edges).expect("Enter Valid Input");
+        let n_edges: i32 = n_edges.trim().parse().expect("Invalid input for edges");
+        //assign the capacity to each edge from the console
+        let net = add_capacities(vertices, n_edges);
+        //get the source and sink vertices
+        let mut source = String::new();
+        print!("Enter Source Vertex : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut source)
+            .expect("Enter valid source vertex ");
+        let source: usize = source.trim().parse().expect("Invalid input for source");
+        let mut sink = String::new();
+        print!("Enter Sink Vertex : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut sink).expect("Enter valid sink vertex ");
+        let sink: usize = sink.trim().parse().expect("Invalid input for sink");
+
+        let result = edmonds_karp(&net, source, sink);
+        println!("******************");
+        println!("Maximum flow (Edmonds-Karp) : {}", result.value);
+        println!("Maximum flow (Dinic) : {}", dinic(&net, source, sink).value);
+        for (e, flow) in net.edges.iter().zip(&result.edge_flows) {
+            println!(
+                "Flow on edge {} -> {} is {} of {}",
+                e.from, e.to, flow, e.capacity
+            );
+        }
+        println!(
+            "Minimum cut : {:?} | {:?}",
+            result.source_side, result.sink_side
+        );
+        let cut: Vec<(usize, usize)> = result
+            .cut_edges
+            .iter()
+            .map(|&i| (net.edges[i].from, net.edges[i].to))
+            .collect();
+        println!("Cut edges : {:?}", cut);
+    }
+
+    //to return the edges entered by the user as a flow network
+    fn add_capacities(vertices: usize, edges: i32) -> FlowNetwork {
+        //intialize a new network with the required number of vertices
+        let mut net = FlowNetwork::new(vertices);
+        for _i in 0..(edges) {
+            //intialize source,destination and capacity
+            let mut s = String::new();
+            let mut d = String::new();
+            let mut c = String::new();
+            //get the source
+            print!("Source : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut s)
+                .expect("Please Enter Valid Input for .");
+            let s: usize = s.trim().parse().expect("Invalid input for source");
+            //get the destination
+            print!("Destination : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut d)
+                .expect("Please Enter Valid Input for .");
+            let d: usize = d.trim().parse().expect("Invalid input for destination");
+            //get the capacity
+            print!("Capacity : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut c)
+                .expect("Please Enter Valid Input for .");
+            let c: i32 = c.trim().parse().expect("Invalid input for capacity");
+            //add edge with source,destination and capacity
+            net.add_edge(s, d, c);
+        }
+        net
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::flow::*;
+
+    fn sample_network() -> FlowNetwork {
+        //The classic CLRS network with maximum flow 23
+        let mut net = FlowNetwork::new(6);
+        net.add_edge(0, 1, 16);
+        net.add_edge(0, 2, 13);
+        net.add_edge(1, 2, 10);
+        net.add_edge(2, 1, 4);
+        net.add_edge(1, 3, 12);
+        net.add_edge(3, 2, 9);
+        net.add_edge(2, 4, 14);
+        net.add_edge(4, 3, 7);
+        net.add_edge(3, 5, 20);
+        net.add_edge(4, 5, 4);
+        net
+    }
+
+    fn check_flow(net: &FlowNetwork, result: &MaxFlow, s: usize, t: usize) {
+        //Capacities are respected and flow is conserved at every inner vertex
+        let mut balance = vec![0; net.vertices];
+        for (e, &f) in net.edges.iter().zip(&result.edge_flows) {
+            assert!(0 <= f && f <= e.capacity);
+            balance[e.from] -= f;
+            balance[e.to] += f;
+        }
+        for (u, &b) in balance.iter().enumerate() {
+            if u == s {
+                assert_eq!(b, -result.value);
+            } else if u == t {
+                assert_eq!(b, result.value);
+            } else {
+                assert_eq!(b, 0);
+            }
+        }
+        //The cut edges are saturated and their capacities add up to the flow value
+        let cut: i32 = result.cut_edges.iter().map(|&i| net.edges[i].capacity).sum();
+        assert_eq!(cut, result.value);
+    }
+
+    #[test]
+    fn test_edmonds_karp() {
+        let net = sample_network();
+        let result = edmonds_karp(&net, 0, 5);
+        assert_eq!(result.value, 23);
+        check_flow(&net, &result, 0, 5);
+        assert_eq!(result.source_side, vec![0, 1, 2, 4]);
+        assert_eq!(result.sink_side, vec![3, 5]);
+    }
+
+    #[test]
+    fn test_dinic() {
+        let net = sample_network();
+        let result = dinic(&net, 0, 5);
+        assert_eq!(result.value, 23);
+        check_flow(&net, &result, 0, 5);
+        assert_eq!(result.source_side, vec![0, 1, 2, 4]);
+    }
+
+    #[test]
+    fn test_unreachable_sink() {
+        let mut net = FlowNetwork::new(4);
+        net.add_edge(0, 1, 5);
+        net.add_edge(2, 3, 5);
+        for result in [edmonds_karp(&net, 0, 3), dinic(&net, 0, 3)] {
+            assert_eq!(result.value, 0);
+            assert_eq!(result.edge_flows, vec![0, 0]);
+            assert_eq!(result.source_side, vec![0, 1]);
+            assert!(result.cut_edges.is_empty());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Source and sink must be different vertices")]
+    fn test_same_source_and_sink() {
+        let net = FlowNetwork::new(2);
+        edmonds_karp(&net, 1, 1);
+    }
+}
@@ -0,0 +1,236 @@
+pub mod hungarian {
+    //Importng necessary libraries
+    use std::io::{stdin, stdout, Write};
+
+    /// A minimum-cost assignment of rows to columns.
+    pub struct Assignment {
+        /// The assigned `(row, column)` pairs in order of the row.
+        pub pairs: Vec<(usize, usize)>,
+        /// The sum of the costs of the assigned cells.
+        pub total_cost: i32,
+    }
+
+    /// The Hungarian algorithm solves the assignment problem in O(n^2 * m) time for an n x m cost matrix with n <= m.
+    /// It keeps a potential for every row and column and grows the assignment one row at a time along shortest augmenting paths.
+    ///
+    /// For a square matrix every row and column is assigned. For a rectangular matrix every row is assigned to a distinct column
+    /// when there are more columns than rows, and every column to a distinct row otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `cost` - The cost matrix, where `cost[i][j]` is the cost of assigning row `i` to column `j`. All rows must have the same length.
+    ///
+    /// # Returns
+    ///
+    /// * `Assignment` - The assigned pairs and their total cost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let cost = vec![
+    ///     vec![4, 1, 3],
+    ///     vec![2, 0, 5],
+    ///     vec![3, 2, 2],
+    /// ];
+    ///
+    /// let result = hungarian(&cost);
+    /// assert_eq!(result.pairs, vec![(0, 1), (1, 0), (2, 2)]);
+    /// assert_eq!(result.total_cost, 5);
+    /// ```
+    pub fn hungarian(cost: &[Vec<i32>]) -> Assignment {
+        let rows = cost.len();
+        let cols = if rows == 0 { 0 } else { cost[0].len() };
+        if rows > cols {
+            //Solve the transposed problem so that there are at least as many columns as rows
+            let transposed: Vec<Vec<i32>> = (0..cols)
+                .map(|j| (0..rows).map(|i| cost[i][j]).collect())
+                .collect();
+            let result = hungarian(&transposed);
+            let mut pairs: Vec<(usize, usize)> = result.pairs.iter().map(|&(j, i)| (i, j)).collect();
+            pairs.sort();
+            return Assignment {
+                pairs,
+                total_cost: result.total_cost,
+            };
+        }
+
+        //Rows and columns are numbered from 1, column 0 is a virtual column holding the row being added
+        let (n, m) = (rows, cols);
+        let mut u = vec![0i64; n + 1]; //row potentials
+        let mut v = vec![0i64; m + 1]; //column potentials
+        let mut row_of = vec![0usize; m + 1]; //row assigned to every column, 0 when free
+        let mut way = vec![0usize; m + 1]; //previous column on the shortest augmenting path
+        for i in 1..=n {
+            row_of[0] = i;
+            let mut j0 = 0;
+            let mut min_to = vec![i64::MAX; m + 1]; //smallest reduced cost reaching every column
+            let mut used = vec![false; m + 1];
+            loop {
+                //Add the cheapest column to the alternating tree
+                used[j0] = true;
+                let i0 = row_of[j0];
+                let mut delta = i64::MAX;
+                let mut j1 = 0;
+                for j in 1..=m {
+                    if !used[j] {
+                        let reduced = cost[i0 - 1][j - 1] as i64 - u[i0] - v[j];
+                        if reduced < min_to[j] {
+                            min_to[j] = reduced;
+                            way[j] = j0;
+                        }
+                        if min_to[j] < delta {
+                            delta = min_to[j];
+                            j1 = j;
+                        }
+                    }
+                }
+                //Shift the potentials so that the new column becomes tight
+                for j in 0..=m {
+                    if used[j] {
+                        u[row_of[j]] += delta;
+                        v[j] -= delta;
+                    } else {
+                        min_to[j] -= delta;
+                    }
+                }
+                j0 = j1;
+                if row_of[j0] == 0 {
+                    break; //Reached a free column
+                }
+            }
+            //Flip the assignment along the augmenting path
+            while j0 != 0 {
+                let j1 = way[j0];
+                row_of[j0] = row_of[j1];
+                j0 = j1;
+            }
+        }
+
+        let mut pairs: Vec<(usize, usize)> = (1..=m)
+            .filter(|&j| row_of[j] != 0)
+            .map(|j| (row_of[j] - 1, j - 1))
+            .collect();
+        pairs.sort();
+        let total_cost = pairs.iter().map(|&(i, j)| cost[i][j]).sum();
+        Assignment { pairs, total_cost }
+    }
+
+    /// Solves the assignment problem for a cost matrix entered by the user.
+    ///
+    /// # Input
+    /// * `no_of_rows` - Input the number of rows (for example workers)
+    /// * `no_of_columns` - Input the number of columns (for example jobs)
+    /// * `cost` - The cost of every cell, row by row
+    ///
+    /// # Output
+    ///
+    /// Prints the assigned pairs and the total cost
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Rows : 2
+    ///Please Enter Number of Columns : 3
+    ///Cost of row 0 column 0 : 4
+    ///Cost of row 0 column 1 : 1
+    ///Cost of row 0 column 2 : 4
+    ///Cost of row 1 column 0 : 2
+    ///Cost of row 1 column 1 : 0
+    ///Cost of row 1 column 2 : 5
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///Row 0 is assigned to column 1 at cost 1
+    ///Row 1 is assigned to column 0 at cost 2
+    ///Total cost : 3
+    /// ```
+    pub fn assignment() {
+        //read the size of the matrix from the console
+        let mut rows = String::new();
+        let mut cols = String::new();
+        println!("******Hungarian Algorithm*********");
+        println!("****************************************************");
+        print!("Please Enter Number of Rows : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut rows).expect("Enter valid number of rows");
+        let rows: usize = rows.trim().parse().expect("Invalid input");
+        print!("Please Enter Number of Columns : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut cols)
+            .expect("Enter valid number of columns");
+        let cols: usize = cols.trim().parse().expect("Invalid input");
+
+        //read the cost of every cell
+        let mut cost = vec![vec![0; cols]; rows];
+        for (i, row) in cost.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                let mut c = String::new();
+                print!("Cost of row {} column {} : ", i, j);
+                let _ = stdout().flush();
+                stdin()
+                    .read_line(&mut c)
+                    .expect("Please Enter Valid Input for cost.");
+                *cell = c.trim().parse().expect("Invalid input for cost");
+            }
+        }
+
+        let result = hungarian(&cost);
+        println!("******************");
+        for &(i, j) in &result.pairs {
+            println!(
+                "Row {} is assigned to column {} at cost {}",
+                i, j, cost[i][j]
+            );
+        }
+        println!("Total cost : {}", result.total_cost);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hungarian::*;
+
+    #[test]
+    fn test_square_matrix() {
+        let cost = vec![
+            vec![9, 2, 7, 8],
+            vec![6, 4, 3, 7],
+            vec![5, 8, 1, 8],
+            vec![7, 6, 9, 4],
+        ];
+        let result = hungarian(&cost);
+        assert_eq!(result.pairs, vec![(0, 1), (1, 0), (2, 2), (3, 3)]);
+        assert_eq!(result.total_cost, 13);
+    }
+
+    #[test]
+    fn test_rectangular_matrix() {
+        //More columns than rows: every row gets a distinct column
+        let cost = vec![vec![4, 1, 4], vec![2, 0, 5]];
+        let result = hungarian(&cost);
+        assert_eq!(result.pairs, vec![(0, 1), (1, 0)]);
+        assert_eq!(result.total_cost, 3);
+
+        //More rows than columns: every column gets a distinct row
+        let cost = vec![vec![4, 2], vec![1, 0], vec![4, 5]];
+        let result = hungarian(&cost);
+        assert_eq!(result.pairs, vec![(0, 1), (1, 0)]);
+        assert_eq!(result.total_cost, 3);
+    }
+
+    #[test]
+    fn test_negative_costs() {
+        //Negative costs work like profits
+        let cost = vec![vec![-5, -3], vec![-4, -1]];
+        let result = hungarian(&cost);
+        assert_eq!(result.pairs, vec![(0, 1), (1, 0)]);
+        assert_eq!(result.total_cost, -7);
+    }
+
+    #[test]
+    fn test_empty_matrix() {
+        let result = hungarian(&[]);
+        assert!(result.pairs.is_empty());
+        assert_eq!(result.total_cost, 0);
+    }
+}
@@ -0,0 +1,517 @@
+pub mod isomorphism {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::dfs::dfs::{add_edges, Graph};
+    use std::collections::{HashMap, VecDeque};
+    use std::io::{stdin, stdout, Write};
+
+    /// The kind of mapping searched for by `matcher`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum MatchKind {
+        /// A bijection between the vertices keeping edges and non-edges.
+        Isomorphism,
+        /// An injection of the pattern into the target keeping edges and non-edges,
+        /// so the pattern is isomorphic to the subgraph induced by the image.
+        InducedSubgraph,
+        /// An injection of the pattern into the target keeping edges. The target may have further edges
+        /// between the image vertices.
+        Subgraph,
+    }
+
+    //Predicates on a pattern and a target vertex, and on a pattern and a target edge
+    type NodeMatch<'a> = Box<dyn Fn(usize, usize) -> bool + 'a>;
+    type EdgeMatch<'a> = Box<dyn Fn((usize, usize), (usize, usize)) -> bool + 'a>;
+
+    //Sorted out-neighbors and in-neighbors of every vertex, ignoring parallel edges
+    struct Adjacency {
+        out: Vec<Vec<usize>>,
+        inn: Vec<Vec<usize>>,
+    }
+
+    impl Adjacency {
+        fn new(g: &Graph) -> Self {
+            let n = g.edges.len();
+            let mut out = g.edges.clone();
+            let mut inn = vec![Vec::new(); n];
+            for (u, adj) in g.edges.iter().enumerate() {
+                for &v in adj {
+                    inn[v].push(u);
+                }
+            }
+            for list in out.iter_mut().chain(inn.iter_mut()) {
+                list.sort_unstable();
+                list.dedup();
+            }
+            Adjacency { out, inn }
+        }
+
+        fn has_edge(&self, u: usize, v: usize) -> bool {
+            self.out[u].binary_search(&v).is_ok()
+        }
+
+        fn edge_count(&self) -> usize {
+            self.out.iter().map(|list| list.len()).sum()
+        }
+    }
+
+    /// A lazy iterator over the mappings from a pattern graph into a target graph, created by `matcher`.
+    /// Every item maps each pattern vertex, by index, to its target vertex.
+    ///
+    /// The search is VF2: pattern vertices are matched one at a time in a fixed order in which every vertex,
+    /// where possible, is adjacent to an earlier one, and a pair is only added when it is consistent with the edges
+    /// to the vertices already matched and the neighbors of both vertices just outside the mapping can still be matched.
+    pub struct Matcher<'a> {
+        pattern: Adjacency,
+        target: Adjacency,
+        kind: MatchKind,
+        node_match: NodeMatch<'a>,
+        edge_match: EdgeMatch<'a>,
+        order: Vec<usize>,
+        core_p: Vec<usize>, //target vertex of every pattern vertex, usize::MAX when unmatched
+        core_t: Vec<usize>, //pattern vertex of every target vertex, usize::MAX when unmatched
+        near_p: Vec<usize>, //number of matched vertices adjacent to every pattern vertex
+        near_t: Vec<usize>,
+        stack: Vec<(Vec<usize>, usize, Option<usize>)>, //candidates, next candidate and current match of every depth
+        started: bool,
+    }
+
+    impl<'a> Matcher<'a> {
+        /// Only matches a pattern vertex to a target vertex when `f(pattern_vertex, target_vertex)` holds.
+        pub fn node_match(mut self, f: impl Fn(usize, usize) -> bool + 'a) -> Self {
+            self.node_match = Box::new(f);
+            self
+        }
+
+        /// Only matches a pattern edge to a target edge when `f(pattern_edge, target_edge)` holds,
+        /// with both edges given as `(source, destination)`.
+        pub fn edge_match(mut self, f: impl Fn((usize, usize), (usize, usize)) -> bool + 'a) -> Self {
+            self.edge_match = Box::new(f);
+            self
+        }
+
+        //Adds or removes the pair (v, w) and updates the counts of matched neighbors
+        fn set(&mut self, v: usize, w: usize, matched: bool) {
+            let (pv, tw) = if matched { (w, v) } else { (usize::MAX, usize::MAX) };
+            self.core_p[v] = pv;
+            self.core_t[w] = tw;
+            for (near, adj, x) in [(&mut self.near_p, &self.pattern, v), (&mut self.near_t, &self.target, w)] {
+                let mut neighbors: Vec<usize> = adj.out[x].iter().chain(&adj.inn[x]).copied().collect();
+                neighbors.sort_unstable();
+                neighbors.dedup();
+                for y in neighbors {
+                    if matched {
+                        near[y] += 1;
+                    } else {
+                        near[y] -= 1;
+                    }
+                }
+            }
+        }
+
+        //Counts the unmatched neighbors of a vertex next to the mapping and further away
+        fn frontier(list: &[usize], core: &[usize], near: &[usize]) -> (usize, usize) {
+            let unmatched = list.iter().filter(|&&y| core[y] == usize::MAX);
+            let close = unmatched.clone().filter(|&&y| near[y] > 0).count();
+            (close, unmatched.count() - close)
+        }
+
+        fn feasible(&self, v: usize, w: usize) -> bool {
+            if self.core_t[w] != usize::MAX || !(self.node_match)(v, w) {
+                return false;
+            }
+            let (p, t) = (&self.pattern, &self.target);
+            let exact = self.kind != MatchKind::Subgraph;
+            //Edges to matched vertices, self-loops included, must exist in the target, and in induced matchings the converse
+            for (p_list, t_list, outgoing) in [(&p.out, &t.out, true), (&p.inn, &t.inn, false)] {
+                for &x in &p_list[v] {
+                    let y = if x == v { w } else { self.core_p[x] };
+                    if y == usize::MAX {
+                        continue;
+                    }
+                    let (pe, te) = if outgoing { ((v, x), (w, y)) } else { ((x, v), (y, w)) };
+                    if !t.has_edge(te.0, te.1) || !(self.edge_match)(pe, te) {
+                        return false;
+                    }
+                }
+                if exact {
+                    for &y in &t_list[w] {
+                        let x = if y == w { v } else { self.core_t[y] };
+                        if x != usize::MAX && !(if outgoing { p.has_edge(v, x) } else { p.has_edge(x, v) }) {
+                            return false;
+                        }
+                    }
+                }
+            }
+            //Look ahead: the unmatched neighbors must still fit
+            for (p_list, t_list) in [(&p.out, &t.out), (&p.inn, &t.inn)] {
+                let (p_close, p_far) = Self::frontier(&p_list[v], &self.core_p, &self.near_p);
+                let (t_close, t_far) = Self::frontier(&t_list[w], &self.core_t, &self.near_t);
+                let fits = match self.kind {
+                    MatchKind::Isomorphism => p_close == t_close && p_far == t_far,
+                    MatchKind::InducedSubgraph => p_close <= t_close && p_far <= t_far,
+                    MatchKind::Subgraph => p_close <= t_close && p_close + p_far <= t_close + t_far,
+                };
+                if !fits {
+                    return false;
+                }
+            }
+            true
+        }
+
+        //The target vertices worth trying for the pattern vertex at a depth: the neighbors of the match
+        //of an adjacent matched vertex, or every target vertex
+        fn candidates(&self, depth: usize) -> Vec<usize> {
+            let v = self.order[depth];
+            if let Some(&x) = self.pattern.inn[v].iter().find(|&&x| self.core_p[x] != usize::MAX) {
+                return self.target.out[self.core_p[x]].clone();
+            }
+            if let Some(&x) = self.pattern.out[v].iter().find(|&&x| self.core_p[x] != usize::MAX) {
+                return self.target.inn[self.core_p[x]].clone();
+            }
+            (0..self.core_t.len()).collect()
+        }
+    }
+
+    impl Iterator for Matcher<'_> {
+        type Item = Vec<usize>;
+
+        fn next(&mut self) -> Option<Vec<usize>> {
+            if !self.started {
+                self.started = true;
+                let (np, nt) = (self.core_p.len(), self.core_t.len());
+                let sizes_fit = match self.kind {
+                    MatchKind::Isomorphism => np == nt && self.pattern.edge_count() == self.target.edge_count(),
+                    _ => np <= nt,
+                };
+                if !sizes_fit {
+                    return None;
+                }
+                if np == 0 {
+                    return Some(Vec::new()); //The empty pattern maps into every target once
+                }
+                let first = self.candidates(0);
+                self.stack.push((first, 0, None));
+            }
+
+            while let Some(depth) = self.stack.len().checked_sub(1) {
+                let v = self.order[depth];
+                if let Some(w) = self.stack[depth].2.take() {
+                    self.set(v, w, false); //Step back from the match tried last at this depth
+                }
+                let mut found = None;
+                while self.stack[depth].1 < self.stack[depth].0.len() {
+                    let w = self.stack[depth].0[self.stack[depth].1];
+                    self.stack[depth].1 += 1;
+                    if self.feasible(v, w) {
+                        found = Some(w);
+                        break;
+                    }
+                }
+                match found {
+                    None => {
+                        self.stack.pop();
+                    }
+                    Some(w) => {
+                        self.set(v, w, true);
+                        self.stack[depth].2 = Some(w);
+                        if depth + 1 == self.order.len() {
+                            return Some(self.core_p.clone());
+                        }
+                        let next = self.candidates(depth + 1);
+                        self.stack.push((next, 0, None));
+                    }
+                }
+            }
+            None
+        }
+    }
+
+    /// Creates an iterator over all mappings of a given kind from `pattern` into `target`.
+    /// Vertex and edge predicates can be added with `Matcher::node_match` and `Matcher::edge_match`,
+    /// for example to compare labels kept alongside the graphs.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - A directed graph built with `dfs::Graph::add_edge`. Parallel edges count once.
+    /// * `target` - The directed graph searched for the pattern.
+    /// * `kind` - Whether to match the whole target, an induced subgraph or any subgraph.
+    ///
+    /// # Returns
+    ///
+    /// * `Matcher` - An iterator yielding the target vertex of every pattern vertex, once per mapping.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //A path a -> b -> c inside a triangle 0 -> 1 -> 2 -> 0
+    /// let mut pattern = Graph::new(3);
+    /// pattern.add_edge(0, 1);
+    /// pattern.add_edge(1, 2);
+    /// let mut target = Graph::new(3);
+    /// for u in 0..3 {
+    ///     target.add_edge(u, (u + 1) % 3);
+    /// }
+    ///
+    /// assert_eq!(matcher(&pattern, &target, MatchKind::Subgraph).count(), 3);
+    /// assert_eq!(matcher(&pattern, &target, MatchKind::InducedSubgraph).count(), 0);
+    ///
+    /// //Only map a onto 2
+    /// let mappings: Vec<Vec<usize>> = matcher(&pattern, &target, MatchKind::Subgraph)
+    ///     .node_match(|v, w| v != 0 || w == 2)
+    ///     .collect();
+    /// assert_eq!(mappings, vec![vec![2, 0, 1]]);
+    ///
+    /// //Isomorphism is the existence of a mapping
+    /// assert!(matcher(&target, &target, MatchKind::Isomorphism).next().is_some());
+    /// ```
+    pub fn matcher<'a>(pattern: &Graph, target: &Graph, kind: MatchKind) -> Matcher<'a> {
+        let pattern = Adjacency::new(pattern);
+        let target = Adjacency::new(target);
+
+        //Breadth-first order from the vertex of largest degree of every component, so that later vertices meet earlier ones
+        let np = pattern.out.len();
+        let degree = |v: usize| pattern.out[v].len() + pattern.inn[v].len();
+        let mut by_degree: Vec<usize> = (0..np).collect();
+        by_degree.sort_by_key(|&v| std::cmp::Reverse(degree(v)));
+        let mut order = Vec::with_capacity(np);
+        let mut seen = vec![false; np];
+        for root in by_degree {
+            if seen[root] {
+                continue;
+            }
+            seen[root] = true;
+            let mut queue = VecDeque::from([root]);
+            while let Some(u) = queue.pop_front() {
+                order.push(u);
+                for &x in pattern.out[u].iter().chain(&pattern.inn[u]) {
+                    if !seen[x] {
+                        seen[x] = true;
+                        queue.push_back(x);
+                    }
+                }
+            }
+        }
+
+        let nt = target.out.len();
+        Matcher {
+            pattern,
+            target,
+            kind,
+            node_match: Box::new(|_, _| true),
+            edge_match: Box::new(|_, _| true),
+            order,
+            core_p: vec![usize::MAX; np],
+            core_t: vec![usize::MAX; nt],
+            near_p: vec![0; np],
+            near_t: vec![0; nt],
+            stack: Vec::new(),
+            started: false,
+        }
+    }
+
+    /// Finds the mappings of a pattern graph into a target graph, both entered by the user.
+    ///
+    /// # Input
+    /// * `no_of_vertices`, `no_of_edges`, `source`, `destination` - The pattern graph, then the target graph
+    /// * `labels` - Optionally a label for every vertex and every edge of both graphs; only equal labels are matched
+    /// * `matching` - 1 for isomorphism, 2 for induced subgraphs, 3 for subgraphs
+    ///
+    /// # Output
+    ///
+    /// Prints every mapping as the target vertex of each pattern vertex
+    ///
+    /// # Sample input
+    /// ```
+    ///Pattern graph
+    /// Please Enter Number of Vertices : 2
+    ///Please Enter Number of edges in the graph : 1
+    ///Source : 0
+    ///Destination : 1
+    ///Target graph
+    /// Please Enter Number of Vertices : 3
+    ///Please Enter Number of edges in the graph : 2
+    ///Source : 0
+    ///Destination : 1
+    ///Source : 1
+    ///Destination : 2
+    ///Enter Vertex Labels? (y/n) : n
+    ///Enter Edge Labels? (y/n) : n
+    ///Enter Kind of Matching (1 = Isomorphism, 2 = Induced Subgraph, 3 = Subgraph) : 3
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///Mapping 1 : [0, 1]
+    ///Mapping 2 : [1, 0]
+    ///Mapping 3 : [1, 2]
+    ///Mapping 4 : [2, 1]
+    /// ```
+    pub fn isomorphism() {
+        println!("*****Graph Isomorphism********");
+        println!("****************************************************");
+        let mut graphs = Vec::new();
+        for name in ["Pattern graph", "Target graph"] {
+            println!("{}", name);
+            //read the number of vertices from the console
+            let mut vertex = String::new();
+            print!("Please Enter Number of Vertices : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut vertex)
+                .expect("Enter valid number of vertices");
+            let vertices: usize = vertex.trim().parse().expect("Invalid input");
+            //get number of edges in the graph
+            let mut n_edges = String::new();
+            print!("Please Enter Number of edges in the graph : ");
+            let _ = stdout().flush();
+            stdin().read_line(&mut n_edges).expect("Enter Valid Input");
+            let n_edges: i32 = n_edges.trim().parse().expect("Invalid input for edges");
+            //assign the vertices to each edge from the console
+            graphs.push(add_edges(vertices, n_edges));
+        }
+        //get the optional labels of the vertices and of the edges, where every edge is entered in both directions
+        let ask = |prompt: &str| {
+            let mut answer = String::new();
+            print!("{}", prompt);
+            let _ = stdout().flush();
+            stdin().read_line(&mut answer).expect("Enter Valid Input");
+            answer.trim().to_string()
+        };
+        let mut vertex_labels: Vec<Vec<String>> = vec![Vec::new(), Vec::new()];
+        if ask("Enter Vertex Labels? (y/n) : ") == "y" {
+            for (i, g) in graphs.iter().enumerate() {
+                for v in 0..g.edges.len() {
+                    vertex_labels[i].push(ask(&format!("Please Enter Label for vertex {} of graph {} : ", v, i + 1)));
+                }
+            }
+        }
+        let mut edge_labels: Vec<HashMap<(usize, usize), String>> = vec![HashMap::new(), HashMap::new()];
+        if ask("Enter Edge Labels? (y/n) : ") == "y" {
+            for (i, g) in graphs.iter().enumerate() {
+                for (u, adj) in g.edges.iter().enumerate() {
+                    for &v in adj {
+                        if u <= v && !edge_labels[i].contains_key(&(u, v)) {
+                            let label = ask(&format!("Please Enter Label for edge {} - {} of graph {} : ", u, v, i + 1));
+                            edge_labels[i].insert((u, v), label);
+                        }
+                    }
+                }
+            }
+        }
+        let edge_label = |i: usize, (u, v): (usize, usize)| edge_labels[i].get(&(u.min(v), u.max(v)));
+        //get the kind of matching
+        let mut choice = String::new();
+        print!("Enter Kind of Matching (1 = Isomorphism, 2 = Induced Subgraph, 3 = Subgraph) : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut choice).expect("Enter Valid Input");
+        let kind = match choice.trim() {
+            "1" => MatchKind::Isomorphism,
+            "2" => MatchKind::InducedSubgraph,
+            "3" => MatchKind::Subgraph,
+            _ => panic!("Invalid Selection for Kind of Matching"),
+        };
+
+        println!("******************");
+        let mut count = 0;
+        let mappings = matcher(&graphs[0], &graphs[1], kind)
+            .node_match(|v, w| vertex_labels[0].get(v) == vertex_labels[1].get(w))
+            .edge_match(|e, f| edge_label(0, e) == edge_label(1, f));
+        for (i, mapping) in mappings.enumerate() {
+            println!("Mapping {} : {:?}", i + 1, mapping);
+            count += 1;
+        }
+        if count == 0 {
+            println!("No mapping exists");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::isomorphism::*;
+    use crate::list_of_algorithms::dfs::dfs::Graph;
+
+    #[test]
+    fn test_isomorphism() {
+        //A directed 4-cycle has 4 automorphisms, its rotations
+        let mut cycle = Graph::new(4);
+        for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 0)] {
+            cycle.add_edge(u, v);
+        }
+        let mut relabeled = Graph::new(4);
+        for (u, v) in [(2, 0), (0, 3), (3, 1), (1, 2)] {
+            relabeled.add_edge(u, v);
+        }
+        let mappings: Vec<Vec<usize>> = matcher(&cycle, &relabeled, MatchKind::Isomorphism).collect();
+        assert_eq!(mappings.len(), 4);
+        for m in &mappings {
+            for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 0)] {
+                assert!(relabeled.edges[m[u]].contains(&m[v]));
+            }
+        }
+        //Reversing one edge breaks the isomorphism, as does a self-loop
+        let isomorphic = |g1: &Graph, g2: &Graph| matcher(g1, g2, MatchKind::Isomorphism).next().is_some();
+        let mut reversed = Graph::new(4);
+        for (u, v) in [(0, 1), (1, 2), (2, 3), (0, 3)] {
+            reversed.add_edge(u, v);
+        }
+        assert!(!isomorphic(&cycle, &reversed));
+        let mut edge = Graph::new(2);
+        edge.add_edge(0, 1);
+        let mut self_loop = Graph::new(2);
+        self_loop.add_edge(0, 0);
+        assert!(!isomorphic(&edge, &self_loop));
+        assert!(isomorphic(&Graph::new(0), &Graph::new(0)));
+    }
+
+    #[test]
+    fn test_subgraph_kinds() {
+        //A fan-in a -> c <- b against a complete directed graph on 4 vertices
+        let mut pattern = Graph::new(3);
+        for (u, v) in [(0, 2), (1, 2)] {
+            pattern.add_edge(u, v);
+        }
+        let mut complete = Graph::new(4);
+        for u in 0..4 {
+            for v in 0..4 {
+                if u != v {
+                    complete.add_edge(u, v);
+                }
+            }
+        }
+        assert_eq!(matcher(&pattern, &complete, MatchKind::Subgraph).count(), 24);
+        assert_eq!(matcher(&pattern, &complete, MatchKind::InducedSubgraph).count(), 0);
+
+        //In a DAG 0 -> 2, 1 -> 2, 2 -> 3, 1 -> 3 only (0, 1, 2) is an induced fan-in, while (1, 2, 3) also has the edge 1 -> 2
+        let mut dag = Graph::new(4);
+        for (u, v) in [(0, 2), (1, 2), (2, 3), (1, 3)] {
+            dag.add_edge(u, v);
+        }
+        let induced: Vec<Vec<usize>> = matcher(&pattern, &dag, MatchKind::InducedSubgraph).collect();
+        assert_eq!(induced.len(), 2);
+        assert!(induced.iter().all(|m| m[2] == 2));
+        assert_eq!(matcher(&pattern, &dag, MatchKind::Subgraph).count(), 4);
+        assert_eq!(matcher(&complete, &pattern, MatchKind::Subgraph).count(), 0);
+    }
+
+    #[test]
+    fn test_predicates() {
+        //Vertex labels and edge weights kept beside the graphs
+        let labels_p = ['a', 'b'];
+        let labels_t = ['b', 'a', 'a'];
+        let mut pattern = Graph::new(2);
+        pattern.add_edge(0, 1);
+        let mut target = Graph::new(3);
+        for (u, v) in [(1, 0), (2, 0), (1, 2)] {
+            target.add_edge(u, v);
+        }
+        let weight = |e: (usize, usize)| if e == (2, 0) { 5 } else { 1 };
+        let mappings: Vec<Vec<usize>> = matcher(&pattern, &target, MatchKind::Subgraph)
+            .node_match(|v, w| labels_p[v] == labels_t[w])
+            .collect();
+        assert_eq!(mappings.len(), 2);
+        let heavy: Vec<Vec<usize>> = matcher(&pattern, &target, MatchKind::Subgraph)
+            .node_match(|v, w| labels_p[v] == labels_t[w])
+            .edge_match(|_, e| weight(e) > 1)
+            .collect();
+        assert_eq!(heavy, vec![vec![2, 0]]);
+    }
+}
@@ -0,0 +1,300 @@
+pub mod k_shortest_paths {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::dijkstras::dijkstras::{add_edges, Graph};
+    use std::io::{stdin, stdout, Write};
+
+    /// A path of a weighted graph together with its cost.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct WeightedPath {
+        /// The vertices of the path in order.
+        pub vertices: Vec<usize>,
+        /// The sum of the weights along the path, using the lightest edge between consecutive vertices.
+        pub cost: i32,
+    }
+
+    //Sums the weights along a path, taking the lightest of any parallel edges
+    fn path_cost(g: &Graph, path: &[usize]) -> i32 {
+        path.windows(2)
+            .map(|step| {
+                g.neighbors(step[0])
+                    .into_iter()
+                    .filter(|&(v, _)| v == step[1])
+                    .map(|(_, w)| w)
+                    .min()
+                    .unwrap_or(i32::MAX)
+            })
+            .sum()
+    }
+
+    /// Yen's algorithm finds the K shortest simple (loopless) paths between two vertices in order of cost.
+    /// Every path after the first is found as a deviation from an earlier one: for each vertex of the previous path,
+    /// the prefix up to it is kept, the edges leaving it along already found paths with the same prefix are removed,
+    /// and `dijkstra` finds the cheapest way on to the target that avoids the prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`, with non-negative weights.
+    /// * `s` - The first vertex of every path.
+    /// * `t` - The last vertex of every path.
+    /// * `k` - The number of paths wanted.
+    ///
+    /// # Returns
+    ///
+    /// * `paths` - Up to `k` distinct simple paths in order of cost.
+    ///   Fewer paths are returned when the graph does not have `k` of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// g.add_edge(0, 1, 1);
+    /// g.add_edge(1, 3, 1);
+    /// g.add_edge(0, 2, 2);
+    /// g.add_edge(2, 3, 2);
+    /// g.add_edge(1, 2, 1);
+    ///
+    /// let paths = k_shortest_paths(&g, 0, 3, 3);
+    /// assert_eq!(paths[0].vertices, vec![0, 1, 3]);
+    /// assert_eq!(paths[1].vertices, vec![0, 1, 2, 3]);
+    /// assert_eq!(paths[2].vertices, vec![0, 2, 3]);
+    /// assert_eq!(paths.iter().map(|p| p.cost).collect::<Vec<_>>(), vec![2, 4, 4]);
+    /// ```
+    pub fn k_shortest_paths(g: &Graph, s: usize, t: usize, k: usize) -> Vec<WeightedPath> {
+        let mut found: Vec<WeightedPath> = Vec::new();
+        if k == 0 {
+            return found;
+        }
+        match g.shortest_path(s, t) {
+            Some(path) => found.push(WeightedPath {
+                cost: path_cost(g, &path),
+                vertices: path,
+            }),
+            None => return found,
+        }
+        let edges = g.edge_list();
+        let mut candidates: Vec<WeightedPath> = Vec::new();
+
+        while found.len() < k {
+            let last = found[found.len() - 1].vertices.clone();
+            for i in 0..last.len() - 1 {
+                let spur = last[i];
+                let root = &last[..=i];
+
+                //Leaving the spur vertex along an earlier path with the same root would repeat that path
+                let mut removed_edges = Vec::new();
+                for p in &found {
+                    if p.vertices.len() > i + 1 && p.vertices[..=i] == *root {
+                        removed_edges.push((spur.min(p.vertices[i + 1]), spur.max(p.vertices[i + 1])));
+                    }
+                }
+                //The rest of the path must not return to the root, so that the path stays simple
+                let mut removed_vertices = vec![false; g.vertex_count()];
+                for &u in &root[..i] {
+                    removed_vertices[u] = true;
+                }
+
+                let mut h = Graph::new(g.vertex_count());
+                for &(u, v, w) in &edges {
+                    if !removed_vertices[u] && !removed_vertices[v] && !removed_edges.contains(&(u, v)) {
+                        h.add_edge(u, v, w);
+                    }
+                }
+                if let Some(spur_path) = h.shortest_path(spur, t) {
+                    let mut vertices = root[..i].to_vec();
+                    vertices.extend(spur_path);
+                    if !candidates.iter().any(|c| c.vertices == vertices) {
+                        candidates.push(WeightedPath {
+                            cost: path_cost(g, &vertices),
+                            vertices,
+                        });
+                    }
+                }
+            }
+
+            //The cheapest candidate is the next shortest path
+            let best = (0..candidates.len()).min_by(|&a, &b| {
+                (candidates[a].cost, &candidates[a].vertices)
+                    .cmp(&(candidates[b].cost, &candidates[b].vertices))
+            });
+            match best {
+                Some(i) => found.push(candidates.swap_remove(i)),
+                None => break, //No further simple path exists
+            }
+        }
+        found
+    }
+
+    /// Finds the K shortest paths between two vertices of a weighted undirected graph entered by the user.
+    ///
+    /// # Inputs
+    ///
+    /// * `vertices` - Total number of vertices in the graph.
+    ///
+    /// * `edges` - Total Number of edges in the graph.
+    ///
+    /// * `Source(s) Destination(d) Weight(w) ` - Source, Destination and Weight for each edge in the graph.
+    ///
+    /// * `start`, `end`, `k` - The endpoints of the paths and the number of paths wanted.
+    ///
+    /// # Output
+    ///
+    /// Prints the paths in order of cost.
+    ///
+    /// # Sample Input
+    /// ```
+    /// Please Enter Number of Vertices in the Graph : 3
+    /// Please Enter Number of edges in the graph : 3
+    /// Please Enter Edge 1 values
+    /// Source : 0
+    /// Destination : 1
+    /// Weight(>0) : 1
+    /// Please Enter Edge 2 values
+    /// Source : 1
+    /// Destination : 2
+    /// Weight(>0) : 1
+    /// Please Enter Edge 3 values
+    /// Source : 0
+    /// Destination : 2
+    /// Weight(>0) : 5
+    /// Please Enter Start Vertex : 0
+    /// Please Enter End Vertex : 2
+    /// Please Enter Number of Paths : 3
+    /// ```
+    /// # Sample Output
+    /// ```
+    /// Path 1 : [0, 1, 2] with cost 2
+    /// Path 2 : [0, 2] with cost 5
+    /// ```
+    pub fn k_shortest() {
+        // Create an empty string to store the number of vertices
+        let mut ve = String::new();
+
+        // Printing the introduction message
+        println!("******K Shortest Paths*******");
+        println!("******************");
+
+        // Prompting user to input number of vertices in the Graph
+        print!("Please Enter Number of Vertices in the Graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut ve)
+            .expect("Please Enter Valid number for vertices.");
+        let vertices: usize = ve
+            .trim()
+            .parse()
+            .expect("Invalid input for number of vertices");
+
+        // Prompting user to input the number of edges
+        let mut edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut edges)
+            .expect("Please Enter Valid Input for number of Edges.");
+        let edges: i32 = edges
+            .trim()
+            .parse()
+            .expect("Invalid input for number of edges");
+
+        // Reading all edges source, destination and weight from the user
+        let g = add_edges(vertices, edges);
+
+        // Prompting user to input the endpoints and the number of paths
+        let mut values = Vec::new();
+        for prompt in [
+            "Please Enter Start Vertex : ",
+            "Please Enter End Vertex : ",
+            "Please Enter Number of Paths : ",
+        ] {
+            let mut value = String::new();
+            print!("{}", prompt);
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut value)
+                .expect("Please Enter Valid Input.");
+            let value: usize = value.trim().parse().expect("Invalid input");
+            values.push(value);
+        }
+
+        println!("******************");
+        for (i, path) in k_shortest_paths(&g, values[0], values[1], values[2])
+            .iter()
+            .enumerate()
+        {
+            println!(
+                "Path {} : {:?} with cost {}",
+                i + 1,
+                path.vertices,
+                path.cost
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::k_shortest_paths::*;
+    use crate::list_of_algorithms::dijkstras::dijkstras::Graph;
+
+    #[test]
+    fn test_yen_classic() {
+        //The example graph from the Wikipedia article on Yen's algorithm made undirected, C..H as 0..5
+        let mut g = Graph::new(6);
+        g.add_edge(0, 1, 3);
+        g.add_edge(0, 2, 2);
+        g.add_edge(1, 3, 4);
+        g.add_edge(2, 1, 1);
+        g.add_edge(2, 3, 2);
+        g.add_edge(2, 4, 3);
+        g.add_edge(3, 4, 2);
+        g.add_edge(3, 5, 1);
+        g.add_edge(4, 5, 2);
+
+        let paths = k_shortest_paths(&g, 0, 5, 3);
+        assert_eq!(paths.len(), 3);
+        assert_eq!(paths[0].vertices, vec![0, 2, 3, 5]);
+        assert_eq!(paths[0].cost, 5);
+        assert_eq!(paths[1].cost, 7);
+        assert_eq!(paths[2].cost, 7); //C-D-E-F-H uses the edge D-E against its direction in the article
+    }
+
+    #[test]
+    fn test_fewer_paths_than_k() {
+        let mut g = Graph::new(4);
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 1);
+        g.add_edge(0, 2, 3);
+        let paths = k_shortest_paths(&g, 0, 2, 10);
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[1].vertices, vec![0, 2]);
+
+        assert!(k_shortest_paths(&g, 0, 3, 2).is_empty()); //Vertex 3 is unreachable
+        assert!(k_shortest_paths(&g, 0, 2, 0).is_empty());
+        assert_eq!(k_shortest_paths(&g, 1, 1, 3)[0].vertices, vec![1]);
+    }
+
+    #[test]
+    fn test_costs_are_ordered_and_paths_distinct() {
+        //A 3 x 3 grid has 12 simple paths between opposite corners
+        let mut g = Graph::new(9);
+        for r in 0..3 {
+            for c in 0..3 {
+                let u = 3 * r + c;
+                if c < 2 {
+                    g.add_edge(u, u + 1, (u + 1) as i32);
+                }
+                if r < 2 {
+                    g.add_edge(u, u + 3, 2);
+                }
+            }
+        }
+        let paths = k_shortest_paths(&g, 0, 8, 20);
+        assert_eq!(paths.len(), 12);
+        for pair in paths.windows(2) {
+            assert!(pair[0].cost <= pair[1].cost);
+        }
+        for (i, p) in paths.iter().enumerate() {
+            assert!(paths[i + 1..].iter().all(|q| q.vertices != p.vertices));
+        }
+    }
+}
@@ -141,6 +141,17 @@ pub mod kosaraju {
     pub fn kosaraju() {
         println!("******Kosaraju Algorithm*******");
         println!("******************");
+        let adj_list = add_neighbors(); // Reading the adjacency list from the user
+
+        // Call the kosaraju function with the adjacency list
+        let res = kosaraju_algorithm(&adj_list);
+        println!("******************");
+        println!("The strongly connected components are:");
+        println!("{:?}", res); // Printing the result of kosaraju algorithm
+    }
+
+    ///To return the adjacency list of a directed graph read from the console
+    pub fn add_neighbors() -> Vec<Vec<usize>> {
         let mut buffer = String::new(); // Create a variable named buffer to read user input
 
         // Read the number of vertices from the user
@@ -183,12 +194,7 @@ pub mod kosaraju {
             }
             adj_list[i] = neighbors;
         }
-
-        // Call the kosaraju function with the adjacency list
-        let res = kosaraju_algorithm(&adj_list);
-        println!("******************");
-        println!("The strongly connected components are:");
-        println!("{:?}", res); // Printing the result of kosaraju algorithm
+        adj_list
     }
 }
 
@@ -0,0 +1,499 @@
+pub mod link_analysis {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::kosaraju::kosaraju::add_neighbors;
+    use std::io::{stdin, stdout, Write};
+
+    /// Where PageRank sends the rank of vertices without outgoing edges.
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Dangling {
+        /// The same distribution as the random jumps, i.e. the personalization vector.
+        Personalization,
+        /// Equally to every vertex.
+        Uniform,
+        /// In proportion to the given non-negative weight of every vertex.
+        Weights(Vec<f64>),
+    }
+
+    /// Parameters of `pagerank`. `PageRankConfig::default()` gives the usual values.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct PageRankConfig {
+        /// The probability of following a link instead of jumping, 0.85 by default.
+        pub damping: f64,
+        /// The iteration stops once the scores change by less than this in total, 1e-6 by default.
+        pub tolerance: f64,
+        /// The largest number of iterations, 100 by default.
+        pub max_iterations: usize,
+        /// The non-negative weight of jumping to every vertex. `None`, the default, jumps uniformly.
+        pub personalization: Option<Vec<f64>>,
+        /// Where the rank of vertices without outgoing edges goes, `Dangling::Personalization` by default.
+        pub dangling: Dangling,
+    }
+
+    impl Default for PageRankConfig {
+        fn default() -> Self {
+            PageRankConfig {
+                damping: 0.85,
+                tolerance: 1e-6,
+                max_iterations: 100,
+                personalization: None,
+                dangling: Dangling::Personalization,
+            }
+        }
+    }
+
+    /// Parameters of `katz`. `KatzConfig::default()` gives the usual values.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct KatzConfig {
+        /// The attenuation of every further step of a walk, 0.1 by default.
+        /// It must be below the reciprocal of the largest eigenvalue of the adjacency matrix for the scores to converge.
+        pub alpha: f64,
+        /// The score every vertex gets on its own, 1.0 by default.
+        pub beta: f64,
+        /// The iteration stops once the scores change by less than this in total, 1e-6 by default.
+        pub tolerance: f64,
+        /// The largest number of iterations, 1000 by default.
+        pub max_iterations: usize,
+    }
+
+    impl Default for KatzConfig {
+        fn default() -> Self {
+            KatzConfig {
+                alpha: 0.1,
+                beta: 1.0,
+                tolerance: 1e-6,
+                max_iterations: 1000,
+            }
+        }
+    }
+
+    /// Scores computed by power iteration.
+    pub struct Scores {
+        /// The score of every vertex.
+        pub values: Vec<f64>,
+        /// The number of iterations performed.
+        pub iterations: usize,
+        /// Whether the scores met the tolerance within the maximum number of iterations.
+        pub converged: bool,
+    }
+
+    /// Hub and authority scores computed by `hits`.
+    pub struct HubsAuthorities {
+        /// How well every vertex points to good authorities. The scores sum to 1.
+        pub hubs: Vec<f64>,
+        /// How well every vertex is pointed to by good hubs. The scores sum to 1.
+        pub authorities: Vec<f64>,
+        /// The number of iterations performed.
+        pub iterations: usize,
+        /// Whether the scores met the tolerance within the maximum number of iterations.
+        pub converged: bool,
+    }
+
+    //Scales non-negative weights to sum to 1, panicking with the given name when that is impossible
+    fn distribution(weights: &[f64], n: usize, name: &str) -> Vec<f64> {
+        if weights.len() != n {
+            panic!("The {} vector must have one entry per vertex", name);
+        }
+        let total: f64 = weights.iter().sum();
+        if weights.iter().any(|&w| w < 0.0) || total <= 0.0 {
+            panic!("The {} vector must be non-negative with a positive sum", name);
+        }
+        weights.iter().map(|w| w / total).collect()
+    }
+
+    /// PageRank scores every vertex by the long-run probability that a random surfer is there, when the surfer follows
+    /// a random outgoing link with probability `damping` and otherwise jumps to a vertex drawn from the personalization
+    /// vector. Computed by power iteration in O(E) time per iteration.
+    ///
+    /// # Arguments
+    ///
+    /// * `adj_list` - A directed graph represented as an adjacency list, as taken by `kosaraju_algorithm`.
+    ///   Parallel edges count as several links.
+    /// * `config` - The damping, tolerance, iteration limit, personalization and dangling-vertex handling.
+    ///
+    /// # Returns
+    ///
+    /// * `Scores` - The PageRank of every vertex, summing to 1.
+    ///
+    /// # Panics
+    ///
+    /// When `damping` is outside `[0, 1]`, or a personalization or dangling weight vector has the wrong length,
+    /// a negative entry or a zero sum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //Vertex 2 is linked from both other vertices
+    /// let adj_list = vec![vec![2], vec![2], vec![0]];
+    ///
+    /// let rank = pagerank(&adj_list, &PageRankConfig::default());
+    /// assert!(rank.converged);
+    /// assert!(rank.values[2] > rank.values[0] && rank.values[0] > rank.values[1]);
+    /// ```
+    pub fn pagerank(adj_list: &[Vec<usize>], config: &PageRankConfig) -> Scores {
+        let n = adj_list.len();
+        if !(0.0..=1.0).contains(&config.damping) {
+            panic!("The damping factor must be between 0 and 1");
+        }
+        if n == 0 {
+            return Scores {
+                values: Vec::new(),
+                iterations: 0,
+                converged: true,
+            };
+        }
+        let jump = match &config.personalization {
+            Some(weights) => distribution(weights, n, "personalization"),
+            None => vec![1.0 / n as f64; n],
+        };
+        let dangling = match &config.dangling {
+            Dangling::Personalization => jump.clone(),
+            Dangling::Uniform => vec![1.0 / n as f64; n],
+            Dangling::Weights(weights) => distribution(weights, n, "dangling"),
+        };
+
+        let mut rank = vec![1.0 / n as f64; n];
+        for iteration in 1..=config.max_iterations {
+            let mut next = vec![0.0; n];
+            let mut dangling_rank = 0.0;
+            for (u, neighbors) in adj_list.iter().enumerate() {
+                if neighbors.is_empty() {
+                    dangling_rank += rank[u];
+                }
+                for &v in neighbors {
+                    next[v] += config.damping * rank[u] / neighbors.len() as f64;
+                }
+            }
+            for v in 0..n {
+                next[v] += config.damping * dangling_rank * dangling[v] + (1.0 - config.damping) * jump[v];
+            }
+            let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+            rank = next;
+            if change < config.tolerance {
+                return Scores {
+                    values: rank,
+                    iterations: iteration,
+                    converged: true,
+                };
+            }
+        }
+        Scores {
+            values: rank,
+            iterations: config.max_iterations,
+            converged: false,
+        }
+    }
+
+    /// Kleinberg's HITS algorithm gives every vertex a hub score, for pointing to good authorities, and an authority
+    /// score, for being pointed to by good hubs. Both are computed together by power iteration.
+    ///
+    /// # Arguments
+    ///
+    /// * `adj_list` - A directed graph represented as an adjacency list.
+    /// * `tolerance` - The iteration stops once the hub scores change by less than this in total.
+    /// * `max_iterations` - The largest number of iterations.
+    ///
+    /// # Returns
+    ///
+    /// * `HubsAuthorities` - The hub and authority scores, each summing to 1, or all 0 when the graph has no edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //Vertices 0 and 1 both link to 2 and 3
+    /// let adj_list = vec![vec![2, 3], vec![2, 3], vec![], vec![]];
+    ///
+    /// let scores = hits(&adj_list, 1e-8, 100);
+    /// assert_eq!(scores.hubs, vec![0.5, 0.5, 0.0, 0.0]);
+    /// assert_eq!(scores.authorities, vec![0.0, 0.0, 0.5, 0.5]);
+    /// ```
+    pub fn hits(adj_list: &[Vec<usize>], tolerance: f64, max_iterations: usize) -> HubsAuthorities {
+        let n = adj_list.len();
+        //Scales the scores so that the largest is 1, leaving all-zero scores alone
+        fn scale_to_max(x: &mut [f64]) {
+            let max = x.iter().copied().fold(0.0, f64::max);
+            if max > 0.0 {
+                x.iter_mut().for_each(|v| *v /= max);
+            }
+        }
+
+        let mut hubs = vec![1.0; n];
+        let mut authorities = vec![0.0; n];
+        let mut iterations = 0;
+        let mut converged = false;
+        while iterations < max_iterations && !converged {
+            iterations += 1;
+            //Authorities collect the hub scores of the vertices linking to them, and hubs the authority scores they link to
+            authorities = vec![0.0; n];
+            for (u, neighbors) in adj_list.iter().enumerate() {
+                for &v in neighbors {
+                    authorities[v] += hubs[u];
+                }
+            }
+            scale_to_max(&mut authorities);
+            let mut next: Vec<f64> = adj_list
+                .iter()
+                .map(|neighbors| neighbors.iter().map(|&v| authorities[v]).sum())
+                .collect();
+            scale_to_max(&mut next);
+            let change: f64 = next.iter().zip(&hubs).map(|(a, b)| (a - b).abs()).sum();
+            hubs = next;
+            converged = change < tolerance;
+        }
+
+        for x in [&mut hubs, &mut authorities] {
+            let total: f64 = x.iter().sum();
+            if total > 0.0 {
+                x.iter_mut().for_each(|v| *v /= total);
+            }
+        }
+        HubsAuthorities {
+            hubs,
+            authorities,
+            iterations,
+            converged,
+        }
+    }
+
+    /// Katz centrality counts the walks ending at every vertex, weighting a walk of length k by `alpha^k`,
+    /// plus `beta` for the vertex itself. Computed by the iteration x = alpha * A^T x + beta.
+    ///
+    /// # Arguments
+    ///
+    /// * `adj_list` - A directed graph represented as an adjacency list. Parallel edges count as several walks.
+    /// * `config` - The attenuation, base score, tolerance and iteration limit.
+    ///
+    /// # Returns
+    ///
+    /// * `Scores` - The Katz centrality of every vertex, scaled to unit Euclidean length.
+    ///   The scores do not converge when `alpha` is too large for the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let adj_list = vec![vec![1], vec![2], vec![]];
+    ///
+    /// let scores = katz(&adj_list, &KatzConfig::default());
+    /// assert!(scores.converged);
+    /// assert!(scores.values[0] < scores.values[1] && scores.values[1] < scores.values[2]);
+    /// ```
+    pub fn katz(adj_list: &[Vec<usize>], config: &KatzConfig) -> Scores {
+        let n = adj_list.len();
+        let mut x = vec![0.0; n];
+        let mut iterations = 0;
+        let mut converged = n == 0;
+        while iterations < config.max_iterations && !converged {
+            iterations += 1;
+            let mut next = vec![config.beta; n];
+            for (u, neighbors) in adj_list.iter().enumerate() {
+                for &v in neighbors {
+                    next[v] += config.alpha * x[u];
+                }
+            }
+            let change: f64 = next.iter().zip(&x).map(|(a, b)| (a - b).abs()).sum();
+            x = next;
+            converged = change < config.tolerance;
+        }
+
+        let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm > 0.0 && norm.is_finite() {
+            x.iter_mut().for_each(|v| *v /= norm);
+        }
+        Scores {
+            values: x,
+            iterations,
+            converged,
+        }
+    }
+
+    /// Scores the vertices of a directed graph entered by the user with PageRank, HITS and Katz centrality.
+    /// PageRank uses the damping factor and dangling-vertex handling entered by the user, the others their defaults.
+    ///
+    /// # Input
+    ///
+    /// * `Number of vertices` - Number of vertices in the graph.
+    /// * `Number of neighbors for each vertex`
+    /// * `Next neighbor for each vertex`
+    /// * `Damping factor` - The probability of following a link in PageRank.
+    /// * `Dangling vertex handling` - Where vertices without outgoing edges send their rank, with a weight per vertex for choice 3.
+    ///
+    /// # Output
+    ///
+    /// Prints the scores of every vertex
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 3
+    /// Please enter the number of neighbors for vertex 0 : 1
+    /// Please enter the next neighbor for vertex 0 : 2
+    /// Please enter the number of neighbors for vertex 1 : 1
+    /// Please enter the next neighbor for vertex 1 : 2
+    /// Please enter the number of neighbors for vertex 2 : 1
+    /// Please enter the next neighbor for vertex 2 : 0
+    /// Please Enter Damping Factor (0-1) : 0.85
+    /// Please Enter Dangling Vertex Handling (1 = Personalization, 2 = Uniform, 3 = Weights) : 1
+    /// ```
+    /// # Sample output
+    /// ```
+    /// PageRank : [0.464, 0.050, 0.486] after 83 iterations
+    /// Katz : [0.581, 0.518, 0.628] after 8 iterations
+    /// Hubs : [0.500, 0.500, 0.000]
+    /// Authorities : [0.000, 0.000, 1.000]
+    /// ```
+    pub fn link_analysis() {
+        println!("******Link Analysis*******");
+        println!("******************");
+        let adj_list = add_neighbors(); // Reading the directed adjacency list from the user
+
+        // Prompting user to input the damping factor and where dangling vertices send their rank
+        let mut damping = String::new();
+        print!("Please Enter Damping Factor (0-1) : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut damping)
+            .expect("Please Enter Valid Input for damping factor.");
+        let damping: f64 = damping.trim().parse().expect("Invalid input for damping factor");
+        let mut choice = String::new();
+        print!("Please Enter Dangling Vertex Handling (1 = Personalization, 2 = Uniform, 3 = Weights) : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut choice).expect("Please Enter Valid Input.");
+        let dangling = match choice.trim() {
+            "1" => Dangling::Personalization,
+            "2" => Dangling::Uniform,
+            "3" => Dangling::Weights(
+                (0..adj_list.len())
+                    .map(|v| {
+                        let mut weight = String::new();
+                        print!("Please Enter Dangling Weight for vertex {} : ", v);
+                        let _ = stdout().flush();
+                        stdin().read_line(&mut weight).expect("Please Enter Valid Input.");
+                        weight.trim().parse().expect("Invalid input for weight")
+                    })
+                    .collect(),
+            ),
+            _ => panic!("Invalid Selection for Dangling Vertex Handling"),
+        };
+        let config = PageRankConfig {
+            damping,
+            dangling,
+            ..PageRankConfig::default()
+        };
+
+        let results = [
+            ("PageRank", pagerank(&adj_list, &config)),
+            ("Katz", katz(&adj_list, &KatzConfig::default())),
+        ];
+        let scores = hits(&adj_list, 1e-8, 100);
+        println!("******************");
+        for (name, result) in &results {
+            println!(
+                "{} : {:.3?} after {} iterations",
+                name, result.values, result.iterations
+            );
+            if !result.converged {
+                println!("{} did not converge", name);
+            }
+        }
+        println!("Hubs : {:.3?}", scores.hubs);
+        println!("Authorities : {:.3?}", scores.authorities);
+        if !scores.converged {
+            println!("HITS did not converge after {} iterations", scores.iterations);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::link_analysis::*;
+
+    fn assert_close(a: &[f64], b: &[f64], eps: f64) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < eps, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_pagerank_symmetric() {
+        //A directed cycle is symmetric, so every vertex gets the same rank
+        let adj_list = vec![vec![1], vec![2], vec![3], vec![0]];
+        let rank = pagerank(&adj_list, &PageRankConfig::default());
+        assert!(rank.converged);
+        assert_close(&rank.values, &[0.25; 4], 1e-9);
+        assert!(pagerank(&[], &PageRankConfig::default()).values.is_empty());
+    }
+
+    #[test]
+    fn test_pagerank_dangling_and_personalization() {
+        //Vertex 1 has no outgoing edges
+        let adj_list = vec![vec![1], vec![]];
+        let rank = pagerank(&adj_list, &PageRankConfig::default());
+        //r1 = 0.85 * r0 + 0.85 * r1 / 2 + 0.15 / 2 with r0 + r1 = 1
+        let r1 = (0.85 + 0.075) / (1.0 + 0.85 - 0.425);
+        assert_close(&rank.values, &[1.0 - r1, r1], 1e-6);
+
+        //Without damping every jump goes to vertex 0, the only personalized vertex
+        let config = PageRankConfig {
+            damping: 0.0,
+            personalization: Some(vec![1.0, 0.0]),
+            ..PageRankConfig::default()
+        };
+        assert_close(&pagerank(&adj_list, &config).values, &[1.0, 0.0], 1e-12);
+
+        //Dangling rank sent to vertex 1 only keeps more rank there than the uniform choice
+        let to_one = PageRankConfig {
+            dangling: Dangling::Weights(vec![0.0, 1.0]),
+            ..PageRankConfig::default()
+        };
+        let uniform = PageRankConfig {
+            dangling: Dangling::Uniform,
+            ..PageRankConfig::default()
+        };
+        assert!(pagerank(&adj_list, &to_one).values[1] > pagerank(&adj_list, &uniform).values[1]);
+
+        let limited = PageRankConfig {
+            max_iterations: 2,
+            tolerance: 0.0,
+            ..PageRankConfig::default()
+        };
+        let rank = pagerank(&adj_list, &limited);
+        assert!(!rank.converged);
+        assert_eq!(rank.iterations, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "personalization")]
+    fn test_pagerank_bad_personalization() {
+        let config = PageRankConfig {
+            personalization: Some(vec![1.0]),
+            ..PageRankConfig::default()
+        };
+        pagerank(&[vec![1], vec![0]], &config);
+    }
+
+    #[test]
+    fn test_hits_and_katz() {
+        //A star pointing outwards: the center is the only hub and the leaves share the authority
+        let adj_list = vec![vec![1, 2, 3], vec![], vec![], vec![]];
+        let scores = hits(&adj_list, 1e-10, 100);
+        assert!(scores.converged);
+        assert_close(&scores.hubs, &[1.0, 0.0, 0.0, 0.0], 1e-12);
+        assert_close(&scores.authorities, &[0.0, 1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0], 1e-12);
+        assert_eq!(hits(&vec![vec![]; 2], 1e-10, 100).hubs, vec![0.0, 0.0]);
+
+        //Katz: each leaf gets beta + alpha * beta, the center beta
+        let scores = katz(&adj_list, &KatzConfig::default());
+        assert!(scores.converged);
+        let raw = [1.0, 1.1, 1.1, 1.1];
+        let norm = raw.iter().map(|v| v * v).sum::<f64>().sqrt();
+        assert_close(&scores.values, &raw.map(|v| v / norm), 1e-9);
+
+        //A complete graph on 4 vertices has largest eigenvalue 3, so alpha = 0.5 diverges
+        let complete: Vec<Vec<usize>> = (0..4).map(|u| (0..4).filter(|&v| v != u).collect()).collect();
+        let config = KatzConfig {
+            alpha: 0.5,
+            max_iterations: 50,
+            ..KatzConfig::default()
+        };
+        assert!(!katz(&complete, &config).converged);
+    }
+}
@@ -0,0 +1,508 @@
+pub mod min_cost_flow {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::bellmanford::bellmanford;
+    use crate::list_of_algorithms::flow::flow::{edmonds_karp, FlowNetwork};
+    use std::{
+        cmp::Reverse,
+        collections::BinaryHeap,
+        io::{stdin, stdout, Write},
+    };
+
+    /// The result of a min-cost flow computation.
+    pub struct MinCostFlow {
+        /// The amount of flow sent from the source to the sink.
+        pub flow: i32,
+        /// The total cost of the flow, the sum of flow times cost over all edges.
+        pub cost: i32,
+        /// The flow on every edge, indexed like `FlowNetwork::edges`.
+        pub edge_flows: Vec<i32>,
+    }
+
+    //Residual graph where edge i of the network becomes arc 2 * i and its reverse arc 2 * i + 1
+    struct Residual {
+        adj: Vec<Vec<usize>>, //arcs leaving every vertex
+        to: Vec<usize>,       //head of every arc
+        cap: Vec<i32>,        //remaining capacity of every arc
+        cost: Vec<i32>,       //cost of every arc, the reverse arc has the negated cost
+    }
+
+    impl Residual {
+        //Builds the residual graph of the network carrying the given flow on every edge
+        fn new(net: &FlowNetwork, edge_flows: &[i32]) -> Self {
+            let mut adj = vec![Vec::new(); net.vertices];
+            let mut to = Vec::new();
+            let mut cap = Vec::new();
+            let mut cost = Vec::new();
+            for (i, e) in net.edges.iter().enumerate() {
+                adj[e.from].push(2 * i);
+                to.push(e.to);
+                cap.push(e.capacity - edge_flows[i]);
+                cost.push(e.cost);
+                adj[e.to].push(2 * i + 1);
+                to.push(e.from);
+                cap.push(edge_flows[i]);
+                cost.push(-e.cost);
+            }
+            Residual { adj, to, cap, cost }
+        }
+
+        //Reads the flow on every edge off the residual graph and prices it
+        fn into_min_cost_flow(self, net: &FlowNetwork, flow: i32) -> MinCostFlow {
+            let edge_flows: Vec<i32> = (0..net.edges.len()).map(|i| self.cap[2 * i + 1]).collect();
+            let cost = net
+                .edges
+                .iter()
+                .zip(&edge_flows)
+                .map(|(e, &f)| e.cost * f)
+                .sum();
+            MinCostFlow {
+                flow,
+                cost,
+                edge_flows,
+            }
+        }
+    }
+
+    /// Sends up to `limit` units of flow from `s` to `t` at minimum cost using successive shortest paths with potentials.
+    ///
+    /// The initial potentials come from the crate's Bellman-Ford algorithm, so edge costs may be negative.
+    /// Every later shortest path is found with Dijkstra's algorithm on the reduced costs, which stay non-negative.
+    /// `dijkstras::Graph` only stores undirected edges, so the directed residual graph runs its own Dijkstra.
+    ///
+    /// # Arguments
+    ///
+    /// * `net` - The flow network with capacities and costs. It must not contain a cycle of negative cost.
+    /// * `s` - The source vertex.
+    /// * `t` - The sink vertex.
+    /// * `limit` - The largest amount of flow to send.
+    ///
+    /// # Returns
+    ///
+    /// * `MinCostFlow` - The amount of flow sent, which is `limit` or the maximum flow if that is smaller, its cost and the flow on every edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics with "Negative weight cycle detected" when a negative-cost cycle is reachable from the source.
+    /// Use `cycle_canceling` for such networks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut net = FlowNetwork::new(4);
+    /// net.add_edge_with_cost(0, 1, 2, 1);
+    /// net.add_edge_with_cost(0, 2, 1, 2);
+    /// net.add_edge_with_cost(1, 2, 1, 1);
+    /// net.add_edge_with_cost(1, 3, 1, 3);
+    /// net.add_edge_with_cost(2, 3, 2, 1);
+    ///
+    /// let result = min_cost_flow(&net, 0, 3, 2);
+    /// assert_eq!((result.flow, result.cost), (2, 6));
+    /// ```
+    pub fn min_cost_flow(net: &FlowNetwork, s: usize, t: usize, limit: i32) -> MinCostFlow {
+        if s == t {
+            panic!("Source and sink must be different vertices");
+        }
+        let n = net.vertices;
+        let mut res = Residual::new(net, &vec![0; net.edges.len()]);
+
+        //Initial potentials are the shortest distances from the source over edges that can carry flow
+        let mut g = bellmanford::Graph::new(n);
+        for e in net.edges.iter().filter(|e| e.capacity > 0) {
+            g.add_edge(e.from, e.to, e.cost);
+        }
+        let mut potential: Vec<i32> = g
+            .bellman_ford(s)
+            .into_iter()
+            .map(|d| if d == i32::MAX { 0 } else { d })
+            .collect();
+
+        let mut flow = 0;
+        while flow < limit {
+            //Dijkstra's algorithm on the reduced costs cost(u, v) + potential[u] - potential[v]
+            let mut dist = vec![i32::MAX; n];
+            let mut parent_arc = vec![usize::MAX; n];
+            let mut heap = BinaryHeap::new();
+            dist[s] = 0;
+            heap.push(Reverse((0, s)));
+            while let Some(Reverse((d, u))) = heap.pop() {
+                if d > dist[u] {
+                    continue; //Stale entry
+                }
+                for &a in &res.adj[u] {
+                    let v = res.to[a];
+                    if res.cap[a] == 0 {
+                        continue;
+                    }
+                    let nd = d + res.cost[a] + potential[u] - potential[v];
+                    if nd < dist[v] {
+                        dist[v] = nd;
+                        parent_arc[v] = a;
+                        heap.push(Reverse((nd, v)));
+                    }
+                }
+            }
+            if dist[t] == i32::MAX {
+                break; //The sink is no longer reachable, the flow is maximum
+            }
+            //Shift the potentials so that reduced costs stay non-negative in the next round
+            for v in 0..n {
+                if dist[v] != i32::MAX {
+                    potential[v] += dist[v];
+                }
+            }
+
+            //Push as much flow as the path allows
+            let mut bottleneck = limit - flow;
+            let mut v = t;
+            while v != s {
+                let a = parent_arc[v];
+                bottleneck = bottleneck.min(res.cap[a]);
+                v = res.to[a ^ 1];
+            }
+            let mut v = t;
+            while v != s {
+                let a = parent_arc[v];
+                res.cap[a] -= bottleneck;
+                res.cap[a ^ 1] += bottleneck;
+                v = res.to[a ^ 1];
+            }
+            flow += bottleneck;
+        }
+        res.into_min_cost_flow(net, flow)
+    }
+
+    /// Finds a maximum flow from `s` to `t` of minimum cost with successive shortest paths, see `min_cost_flow`.
+    pub fn min_cost_max_flow(net: &FlowNetwork, s: usize, t: usize) -> MinCostFlow {
+        min_cost_flow(net, s, t, i32::MAX)
+    }
+
+    //Bellman-Ford from a virtual vertex joined to every vertex, returns the arcs of a negative cycle if there is one
+    fn negative_cycle(res: &Residual) -> Option<Vec<usize>> {
+        let n = res.adj.len();
+        let mut dist = vec![0i64; n];
+        let mut parent_arc = vec![usize::MAX; n];
+        let mut last = None;
+        for _ in 0..n {
+            last = None;
+            for (u, arcs) in res.adj.iter().enumerate() {
+                for &a in arcs {
+                    let v = res.to[a];
+                    if res.cap[a] > 0 && dist[u] + (res.cost[a] as i64) < dist[v] {
+                        dist[v] = dist[u] + res.cost[a] as i64;
+                        parent_arc[v] = a;
+                        last = Some(v);
+                    }
+                }
+            }
+            last?; //Nothing changed, so there is no negative cycle
+        }
+        //A vertex relaxed in the n-th round leads back into a negative cycle after n steps
+        let mut v = last?;
+        for _ in 0..n {
+            v = res.to[parent_arc[v] ^ 1];
+        }
+        let mut cycle = Vec::new();
+        let mut u = v;
+        loop {
+            let a = parent_arc[u];
+            cycle.push(a);
+            u = res.to[a ^ 1];
+            if u == v {
+                break;
+            }
+        }
+        Some(cycle)
+    }
+
+    /// Finds a maximum flow from `s` to `t` of minimum cost by cycle canceling.
+    ///
+    /// A maximum flow is computed with Edmonds-Karp first, then Bellman-Ford repeatedly finds a negative-cost cycle
+    /// in the residual graph and pushes flow around it until none is left. Unlike `min_cost_flow` this also handles
+    /// networks that contain negative-cost cycles.
+    ///
+    /// # Arguments
+    ///
+    /// * `net` - The flow network with capacities and costs.
+    /// * `s` - The source vertex.
+    /// * `t` - The sink vertex.
+    ///
+    /// # Returns
+    ///
+    /// * `MinCostFlow` - The maximum flow value, its minimum cost and the flow on every edge.
+    pub fn cycle_canceling(net: &FlowNetwork, s: usize, t: usize) -> MinCostFlow {
+        let max_flow = edmonds_karp(net, s, t);
+        let mut res = Residual::new(net, &max_flow.edge_flows);
+        while let Some(cycle) = negative_cycle(&res) {
+            //Pushing flow around the cycle keeps the flow value and lowers the cost
+            let bottleneck = cycle.iter().map(|&a| res.cap[a]).min().unwrap_or(0);
+            for &a in &cycle {
+                res.cap[a] -= bottleneck;
+                res.cap[a ^ 1] += bottleneck;
+            }
+        }
+
+        res.into_min_cost_flow(net, max_flow.value)
+    }
+
+    /// Computes a minimum cost maximum flow of a network entered by the user.
+    ///
+    /// # Input
+    /// * `no_of_vertices` - Input the number of vertices in the network
+    /// * `no_of_edges` - Input the number of edges in the network
+    /// * `source` - The source vertex of an edge in the network
+    /// * `destination` - The destination of an edge in the network
+    /// * `capacity` - The capacity of the corresponding edge
+    /// * `cost` - The cost per unit of flow on the corresponding edge, which may be negative
+    /// * `Source vertex` and `Sink vertex` - The vertices between which the flow is computed
+    ///
+    /// # Output
+    ///
+    /// Prints the flow value and cost found by successive shortest paths and by cycle canceling, and the flow on every edge.
+    /// When the network has a negative-cost cycle only cycle canceling applies.
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 4
+    ///Please Enter Number of edges in the network : 5
+    ///Source : 0
+    ///Destination : 1
+    ///Capacity : 2
+    ///Cost : 1
+    ///Source : 0
+    ///Destination : 2
+    ///Capacity : 1
+    ///Cost : 2
+    ///Source : 1
+    ///Destination : 2
+    ///Capacity : 1
+    ///Cost : 1
+    ///Source : 1
+    ///Destination : 3
+    ///Capacity : 1
+    ///Cost : 3
+    ///Source : 2
+    ///Destination : 3
+    ///Capacity : 2
+    ///Cost : 1
+    ///Enter Source Vertex : 0
+    ///Enter Sink Vertex : 3
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///Successive shortest paths : flow 3 with cost 10
+    ///Cycle canceling : flow 3 with cost 10
+    ///Flow on edge 0 -> 1 is 2 of 2 at cost 1
+    ///Flow on edge 0 -> 2 is 1 of 1 at cost 2
+    ///Flow on edge 1 -> 2 is 1 of 1 at cost 1
+    ///Flow on edge 1 -> 3 is 1 of 1 at cost 3
+    ///Flow on edge 2 -> 3 is 2 of 2 at cost 1
+    /// ```
+    pub fn min_cost() {
+        //read the number of vertices from the console
+        let mut vertex = String::new();
+        println!("********Min-Cost Flow***********");
+        println!("****************************************************");
+        //get the number of vertices
+        print!("Please Enter Number of Vertices : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut vertex)
+            .expect("Enter valid number of vertices");
+        let vertices: usize = vertex.trim().parse().expect("Invalid input");
+        //get number of edges in the network
+        let mut n_edges = String::new();
+        print!("Please Enter Number of edges in the network : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut n_edges).expect("Enter Valid Input");
+        let n_edges: i32 = n_edges.trim().parse().expect("Invalid input for edges");
+        //assign the capacity and cost to each edge from the console
+        let net = add_costs(vertices, n_edges);
+        //get the source and sink vertices
+        let mut source = String::new();
+        print!("Enter Source Vertex : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut source)
+            .expect("Enter valid source vertex ");
+        let source: usize = source.trim().parse().expect("Invalid input for source");
+        let mut sink = String::new();
+        print!("Enter Sink Vertex : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut sink).expect("Enter valid sink vertex ");
+        let sink: usize = sink.trim().parse().expect("Invalid input for sink");
+
+        println!("******************");
+        for line in report(&net, source, sink) {
+            println!("{}", line);
+        }
+    }
+
+    //The lines printed by the driver. Successive shortest paths panics on a negative-cost cycle,
+    //so the network is checked first and only cycle canceling runs when it has one
+    pub(super) fn report(net: &FlowNetwork, s: usize, t: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+        if negative_cycle(&Residual::new(net, &vec![0; net.edges.len()])).is_some() {
+            lines.push("Successive shortest paths : not applicable, the network has a negative-cost cycle".to_string());
+        } else {
+            let result = min_cost_max_flow(net, s, t);
+            lines.push(format!(
+                "Successive shortest paths : flow {} with cost {}",
+                result.flow, result.cost
+            ));
+        }
+        let canceled = cycle_canceling(net, s, t);
+        lines.push(format!(
+            "Cycle canceling : flow {} with cost {}",
+            canceled.flow, canceled.cost
+        ));
+        for (e, flow) in net.edges.iter().zip(&canceled.edge_flows) {
+            lines.push(format!(
+                "Flow on edge {} -> {} is {} of {} at cost {}",
+                e.from, e.to, flow, e.capacity, e.cost
+            ));
+        }
+        lines
+    }
+
+    //to return the edges entered by the user as a flow network with costs
+    fn add_costs(vertices: usize, edges: i32) -> FlowNetwork {
+        //intialize a new network with the required number of vertices
+        let mut net = FlowNetwork::new(vertices);
+        for _i in 0..(edges) {
+            //intialize source,destination,capacity and cost
+            let mut s = String::new();
+            let mut d = String::new();
+            let mut c = String::new();
+            let mut w = String::new();
+            //get the source
+            print!("Source : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut s)
+                .expect("Please Enter Valid Input for .");
+            let s: usize = s.trim().parse().expect("Invalid input for source");
+            //get the destination
+            print!("Destination : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut d)
+                .expect("Please Enter Valid Input for .");
+            let d: usize = d.trim().parse().expect("Invalid input for destination");
+            //get the capacity
+            print!("Capacity : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut c)
+                .expect("Please Enter Valid Input for .");
+            let c: i32 = c.trim().parse().expect("Invalid input for capacity");
+            //get the cost
+            print!("Cost : ");
+            let _ = stdout().flush();
+            stdin()
+                .read_line(&mut w)
+                .expect("Please Enter Valid Input for .");
+            let w: i32 = w.trim().parse().expect("Invalid input for cost");
+            //add edge with source,destination,capacity and cost
+            net.add_edge_with_cost(s, d, c, w);
+        }
+        net
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::min_cost_flow::*;
+    use crate::list_of_algorithms::flow::flow::FlowNetwork;
+
+    fn sample_network() -> FlowNetwork {
+        let mut net = FlowNetwork::new(4);
+        net.add_edge_with_cost(0, 1, 2, 1);
+        net.add_edge_with_cost(0, 2, 1, 2);
+        net.add_edge_with_cost(1, 2, 1, 1);
+        net.add_edge_with_cost(1, 3, 1, 3);
+        net.add_edge_with_cost(2, 3, 2, 1);
+        net
+    }
+
+    #[test]
+    fn test_min_cost_max_flow() {
+        let net = sample_network();
+        for result in [min_cost_max_flow(&net, 0, 3), cycle_canceling(&net, 0, 3)] {
+            assert_eq!(result.flow, 3);
+            assert_eq!(result.cost, 10);
+            assert_eq!(result.edge_flows, vec![2, 1, 1, 1, 2]);
+        }
+    }
+
+    #[test]
+    fn test_flow_limit() {
+        let net = sample_network();
+        let result = min_cost_flow(&net, 0, 3, 2);
+        assert_eq!(result.flow, 2);
+        assert_eq!(result.cost, 6);
+    }
+
+    #[test]
+    fn test_negative_costs() {
+        //Two workers and two jobs where negative costs are profits, the best pairing is 1-4 and 2-3
+        let mut net = FlowNetwork::new(6);
+        net.add_edge_with_cost(0, 1, 1, 0);
+        net.add_edge_with_cost(0, 2, 1, 0);
+        net.add_edge_with_cost(1, 3, 1, -5);
+        net.add_edge_with_cost(1, 4, 1, -3);
+        net.add_edge_with_cost(2, 3, 1, -4);
+        net.add_edge_with_cost(2, 4, 1, -1);
+        net.add_edge_with_cost(3, 5, 1, 0);
+        net.add_edge_with_cost(4, 5, 1, 0);
+
+        for result in [min_cost_max_flow(&net, 0, 5), cycle_canceling(&net, 0, 5)] {
+            assert_eq!(result.flow, 2);
+            assert_eq!(result.cost, -7);
+            assert_eq!(result.edge_flows, vec![1, 1, 0, 1, 1, 0, 1, 1]);
+        }
+    }
+
+    #[test]
+    fn test_negative_cycle_is_canceled() {
+        //The cycle 1 -> 2 -> 1 has cost -2 and capacity 2, so it is saturated on top of the single unit of flow
+        let mut net = FlowNetwork::new(4);
+        net.add_edge_with_cost(0, 1, 1, 1);
+        net.add_edge_with_cost(1, 3, 1, 1);
+        net.add_edge_with_cost(1, 2, 2, -3);
+        net.add_edge_with_cost(2, 1, 2, 1);
+
+        let result = cycle_canceling(&net, 0, 3);
+        assert_eq!(result.flow, 1);
+        assert_eq!(result.cost, -2);
+        assert_eq!(result.edge_flows, vec![1, 1, 2, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Negative weight cycle detected")]
+    fn test_negative_cycle_successive_shortest_paths() {
+        let mut net = FlowNetwork::new(3);
+        net.add_edge_with_cost(0, 1, 1, 1);
+        net.add_edge_with_cost(1, 2, 1, -3);
+        net.add_edge_with_cost(2, 1, 1, 1);
+        min_cost_max_flow(&net, 0, 2);
+    }
+
+    #[test]
+    fn test_report_with_negative_cycle() {
+        //The driver must not reach successive shortest paths, which would panic on the cycle 1 -> 2 -> 1
+        let mut net = FlowNetwork::new(3);
+        net.add_edge_with_cost(0, 1, 1, 1);
+        net.add_edge_with_cost(1, 2, 1, -3);
+        net.add_edge_with_cost(2, 1, 1, 1);
+        assert_eq!(
+            report(&net, 0, 2),
+            vec![
+                "Successive shortest paths : not applicable, the network has a negative-cost cycle",
+                "Cycle canceling : flow 1 with cost -2",
+                "Flow on edge 0 -> 1 is 1 of 1 at cost 1",
+                "Flow on edge 1 -> 2 is 1 of 1 at cost -3",
+                "Flow on edge 2 -> 1 is 0 of 1 at cost 1",
+            ]
+        );
+    }
+}
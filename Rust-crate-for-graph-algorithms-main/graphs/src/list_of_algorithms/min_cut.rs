@@ -0,0 +1,398 @@
+pub mod min_cut {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::bfs::bfs;
+    use crate::list_of_algorithms::dijkstras::dijkstras::{add_edges, Graph};
+    use crate::list_of_algorithms::flow::flow::{dinic, FlowNetwork};
+    use std::io::{stdin, stdout, Write};
+
+    /// A global minimum cut found by `stoer_wagner`.
+    #[derive(Debug, PartialEq)]
+    pub struct MinCut {
+        /// The total weight of the edges crossing the cut, which may exceed the range of a single `i32` weight.
+        pub weight: i64,
+        /// The side of the cut containing vertex 0, in sorted order.
+        pub left: Vec<usize>,
+        /// The other side of the cut, in sorted order. It is never empty.
+        pub right: Vec<usize>,
+        /// The edges crossing the cut as `(u, v, w)` with `u < v`.
+        pub cut_edges: Vec<(usize, usize, i32)>,
+    }
+
+    /// The Stoer–Wagner algorithm finds a global minimum cut of a weighted undirected graph: a division of the vertices
+    /// into two non-empty sides with the smallest total weight of edges between them. Every phase orders the vertices
+    /// by maximum adjacency, adding next the vertex most strongly connected to those already added; the cut separating
+    /// the last vertex from the rest is a minimum cut between the last two vertices, which are then merged.
+    /// The lightest of these phase cuts is a global minimum cut. Runs in O(V^3) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`, with non-negative weights.
+    ///   Parallel edges add up and self-loops are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `MinCut` - The weight of the cut, its two sides and the edges crossing it.
+    ///   A disconnected graph has a cut of weight 0.
+    ///
+    /// # Panics
+    ///
+    /// When the graph has fewer than two vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //Two heavy triangles joined by edges of weight 1 and 2
+    /// let mut g = Graph::new(6);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+    ///     g.add_edge(u, v, 5);
+    /// }
+    /// g.add_edge(0, 3, 1);
+    /// g.add_edge(2, 5, 2);
+    ///
+    /// let cut = stoer_wagner(&g);
+    /// assert_eq!(cut.weight, 3);
+    /// assert_eq!(cut.left, vec![0, 1, 2]);
+    /// assert_eq!(cut.cut_edges, vec![(0, 3, 1), (2, 5, 2)]);
+    /// ```
+    pub fn stoer_wagner(g: &Graph) -> MinCut {
+        let n = g.vertex_count();
+        if n < 2 {
+            panic!("A cut needs at least two vertices");
+        }
+        let edges = g.edge_list();
+        let mut weight = vec![vec![0i64; n]; n];
+        for &(u, v, w) in &edges {
+            if u != v {
+                weight[u][v] += w as i64;
+                weight[v][u] += w as i64;
+            }
+        }
+
+        let mut merged: Vec<Vec<usize>> = (0..n).map(|v| vec![v]).collect(); //original vertices behind every vertex
+        let mut active: Vec<usize> = (0..n).collect();
+        let mut best = i64::MAX;
+        let mut best_side = Vec::new();
+        while active.len() > 1 {
+            //Maximum adjacency ordering: key is the weight from a vertex to those already added
+            let mut key = vec![0i64; n];
+            let mut added = vec![false; n];
+            let mut previous = active[0];
+            for step in 0..active.len() {
+                let v = active
+                    .iter()
+                    .copied()
+                    .filter(|&x| !added[x])
+                    .fold(None, |best: Option<usize>, x| match best {
+                        Some(b) if key[b] >= key[x] => Some(b),
+                        _ => Some(x),
+                    })
+                    .expect("Every step adds an active vertex");
+                added[v] = true;
+                if step + 1 < active.len() {
+                    for &x in &active {
+                        key[x] += weight[v][x];
+                    }
+                    previous = v;
+                    continue;
+                }
+
+                //The cut of the phase separates the last vertex from the rest, then the last two vertices are merged
+                if key[v] < best {
+                    best = key[v];
+                    best_side = merged[v].clone();
+                }
+                let moved = std::mem::take(&mut merged[v]);
+                merged[previous].extend(moved);
+                for &x in &active {
+                    weight[previous][x] += weight[v][x];
+                    weight[x][previous] = weight[previous][x];
+                }
+                weight[previous][previous] = 0;
+                active.retain(|&x| x != v);
+            }
+        }
+
+        let mut on_side = vec![false; n];
+        for &v in &best_side {
+            on_side[v] = true;
+        }
+        if on_side[0] {
+            on_side.iter_mut().for_each(|b| *b = !*b);
+        }
+        MinCut {
+            weight: best,
+            left: (0..n).filter(|&v| !on_side[v]).collect(),
+            right: (0..n).filter(|&v| on_side[v]).collect(),
+            cut_edges: edges.into_iter().filter(|&(u, v, _)| on_side[u] != on_side[v]).collect(),
+        }
+    }
+
+    /// Computes the edge connectivity of an undirected graph: the fewest edges whose removal disconnects it.
+    /// This is the smallest maximum flow from vertex 0 to any other vertex with every edge of capacity 1 in both
+    /// directions, each computed with `flow::dinic`.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Parallel edges count separately and self-loops are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `connectivity` - The edge connectivity, 0 for a disconnected graph or one with fewer than two vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //A cycle survives the loss of any one edge, but not of two
+    /// let mut g = bfs::Graph::new(5);
+    /// for u in 0..5 {
+    ///     g.add_edge(u, (u + 1) % 5);
+    /// }
+    /// assert_eq!(edge_connectivity(&g), 2);
+    /// ```
+    pub fn edge_connectivity(g: &bfs::Graph) -> usize {
+        let n = g.edges.len();
+        let mut net = FlowNetwork::new(n);
+        for (u, adj) in g.edges.iter().enumerate() {
+            for &v in adj {
+                if u != v {
+                    net.add_edge(u, v, 1); //Every undirected edge is stored at both ends, giving one arc each way
+                }
+            }
+        }
+        (1..n).map(|t| dinic(&net, 0, t).value as usize).min().unwrap_or(0)
+    }
+
+    /// Computes the vertex connectivity of an undirected graph: the fewest vertices whose removal disconnects it,
+    /// or leaves a single vertex. Every vertex is split into an entry and an exit joined by an arc of capacity 1,
+    /// so that the maximum flow between two non-adjacent vertices counts the vertex-disjoint paths between them.
+    /// Following Even, only pairs whose first vertex is among the first k + 1 vertices need checking,
+    /// where k is the smallest value found so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - An undirected graph built with `bfs::Graph::add_edge`. Self-loops and parallel edges are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `connectivity` - The vertex connectivity, which is V - 1 for a complete graph
+    ///   and 0 for a disconnected graph or one with fewer than two vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //Two triangles sharing vertex 2 fall apart without it
+    /// let mut g = bfs::Graph::new(5);
+    /// for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)] {
+    ///     g.add_edge(u, v);
+    /// }
+    /// assert_eq!(vertex_connectivity(&g), 1);
+    /// assert_eq!(edge_connectivity(&g), 2);
+    /// ```
+    pub fn vertex_connectivity(g: &bfs::Graph) -> usize {
+        let n = g.edges.len();
+        if n < 2 {
+            return 0;
+        }
+        let mut adjacent = vec![vec![false; n]; n];
+        let mut net = FlowNetwork::new(2 * n); //vertex v enters at 2 * v and leaves at 2 * v + 1
+        for (u, adj) in g.edges.iter().enumerate() {
+            net.add_edge(2 * u, 2 * u + 1, 1);
+            for &v in adj {
+                if u != v && !adjacent[u][v] {
+                    adjacent[u][v] = true;
+                    net.add_edge(2 * u + 1, 2 * v, n as i32);
+                }
+            }
+        }
+
+        let mut best = n - 1;
+        let mut i = 0;
+        while i <= best && i < n {
+            for (j, &joined) in adjacent[i].iter().enumerate().skip(i + 1) {
+                if !joined {
+                    best = best.min(dinic(&net, 2 * i + 1, 2 * j).value as usize);
+                }
+            }
+            i += 1;
+        }
+        best
+    }
+
+    /// Computes a global minimum cut of a weighted undirected graph entered by the user, and the edge and
+    /// vertex connectivity of the same graph without its weights.
+    ///
+    /// # Inputs
+    ///
+    /// * `vertices` - Total number of vertices in the graph.
+    ///
+    /// * `edges` - Total Number of edges in the graph.
+    ///
+    /// * `Source(s) Destination(d) Weight(w) ` - Source, Destination and Weight for each edge in the graph.
+    ///
+    /// # Output
+    ///
+    /// Prints the minimum cut with its weight and crossing edges, and both connectivities.
+    ///
+    /// # Sample Input
+    /// ```
+    /// Please Enter Number of Vertices in the Graph : 4
+    /// Please Enter Number of edges in the graph : 4
+    /// Please Enter Edge 1 values
+    /// Source : 0
+    /// Destination : 1
+    /// Weight(>0) : 3
+    /// Please Enter Edge 2 values
+    /// Source : 1
+    /// Destination : 2
+    /// Weight(>0) : 1
+    /// Please Enter Edge 3 values
+    /// Source : 2
+    /// Destination : 3
+    /// Weight(>0) : 3
+    /// Please Enter Edge 4 values
+    /// Source : 3
+    /// Destination : 0
+    /// Weight(>0) : 1
+    /// ```
+    /// # Sample Output
+    /// ```
+    /// Minimum cut : [0, 1] | [2, 3] with weight 2
+    /// Cut edges : [(0, 3, 1), (1, 2, 1)]
+    /// Edge connectivity : 2
+    /// Vertex connectivity : 2
+    /// ```
+    pub fn min_cut() {
+        // Create an empty string to store the number of vertices
+        let mut ve = String::new();
+
+        // Printing the introduction message
+        println!("******Minimum Cut and Connectivity*******");
+        println!("******************");
+
+        // Prompting user to input number of vertices in the Graph
+        print!("Please Enter Number of Vertices in the Graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut ve)
+            .expect("Please Enter Valid number for vertices.");
+        let vertices: usize = ve
+            .trim()
+            .parse()
+            .expect("Invalid input for number of vertices");
+
+        // Prompting user to input the number of edges
+        let mut edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut edges)
+            .expect("Please Enter Valid Input for number of Edges.");
+        let edges: i32 = edges
+            .trim()
+            .parse()
+            .expect("Invalid input for number of edges");
+
+        // Reading all edges source, destination and weight from the user
+        let g = add_edges(vertices, edges);
+        // The connectivities count the edges of the same graph
+        let mut unweighted = bfs::Graph::new(vertices);
+        for (u, v, _) in g.edge_list() {
+            unweighted.add_edge(u, v);
+        }
+
+        println!("******************");
+        let cut = stoer_wagner(&g);
+        println!(
+            "Minimum cut : {:?} | {:?} with weight {}",
+            cut.left, cut.right, cut.weight
+        );
+        println!("Cut edges : {:?}", cut.cut_edges);
+        println!("Edge connectivity : {}", edge_connectivity(&unweighted));
+        println!("Vertex connectivity : {}", vertex_connectivity(&unweighted));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::min_cut::*;
+    use crate::list_of_algorithms::bfs::bfs;
+    use crate::list_of_algorithms::dijkstras::dijkstras::Graph;
+
+    #[test]
+    fn test_stoer_wagner() {
+        //The example graph of Stoer and Wagner, vertices 1..8 as 0..7, with minimum cut 4
+        let mut g = Graph::new(8);
+        for (u, v, w) in [
+            (0, 1, 2),
+            (0, 4, 3),
+            (1, 2, 3),
+            (1, 4, 2),
+            (1, 5, 2),
+            (2, 3, 4),
+            (2, 6, 2),
+            (3, 6, 2),
+            (3, 7, 2),
+            (4, 5, 3),
+            (5, 6, 1),
+            (6, 7, 3),
+        ] {
+            g.add_edge(u, v, w);
+        }
+        let cut = stoer_wagner(&g);
+        assert_eq!(cut.weight, 4);
+        assert_eq!(cut.left, vec![0, 1, 4, 5]);
+        assert_eq!(cut.right, vec![2, 3, 6, 7]);
+        assert_eq!(cut.cut_edges.iter().map(|e| e.2).sum::<i32>(), 4);
+
+        //A disconnected graph, and parallel edges adding up
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1, 2);
+        g.add_edge(0, 1, 2);
+        assert_eq!(stoer_wagner(&g).weight, 0);
+        g.add_edge(1, 2, 3);
+        assert_eq!(stoer_wagner(&g).right, vec![2]);
+
+        //Heavy parallel edges whose total does not fit in an i32
+        let mut g = Graph::new(3);
+        for (u, v) in [(0, 1), (0, 1), (1, 2), (1, 2)] {
+            g.add_edge(u, v, i32::MAX);
+        }
+        assert_eq!(stoer_wagner(&g).weight, 2 * i32::MAX as i64);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least two vertices")]
+    fn test_stoer_wagner_single_vertex() {
+        stoer_wagner(&Graph::new(1));
+    }
+
+    #[test]
+    fn test_connectivity() {
+        //The complete bipartite graph K(3, 4) has both connectivities 3
+        let mut g = bfs::Graph::new(7);
+        for u in 0..3 {
+            for v in 3..7 {
+                g.add_edge(u, v);
+            }
+        }
+        assert_eq!(edge_connectivity(&g), 3);
+        assert_eq!(vertex_connectivity(&g), 3);
+
+        //K5 is complete, and removing vertices never disconnects it
+        let mut g = bfs::Graph::new(5);
+        for u in 0..5 {
+            for v in u + 1..5 {
+                g.add_edge(u, v);
+            }
+        }
+        assert_eq!(vertex_connectivity(&g), 4);
+        assert_eq!(edge_connectivity(&g), 4);
+
+        let mut g = bfs::Graph::new(4);
+        g.add_edge(0, 1);
+        g.add_edge(2, 3);
+        assert_eq!(edge_connectivity(&g), 0);
+        assert_eq!(vertex_connectivity(&g), 0);
+        assert_eq!(vertex_connectivity(&bfs::Graph::new(1)), 0);
+    }
+}
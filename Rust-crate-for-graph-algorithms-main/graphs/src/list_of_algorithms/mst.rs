@@ -0,0 +1,311 @@
+pub mod mst {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::connected_components::connected_components::UnionFind;
+    use crate::list_of_algorithms::dijkstras::dijkstras::{add_edges, Graph};
+    use std::{
+        cmp::Reverse,
+        collections::BinaryHeap,
+        io::{stdin, stdout, Write},
+    };
+
+    /// A minimum spanning tree, or a minimum spanning forest when the graph is disconnected.
+    pub struct SpanningTree {
+        /// The edges of the tree as `(u, v, w)` in the order they were added.
+        pub edges: Vec<(usize, usize, i32)>,
+        /// The sum of the weights of the tree edges.
+        pub total_weight: i32,
+    }
+
+    impl SpanningTree {
+        //Builds the tree from its edges and sums their weights
+        fn from_edges(edges: Vec<(usize, usize, i32)>) -> Self {
+            let total_weight = edges.iter().map(|&(_, _, w)| w).sum();
+            SpanningTree {
+                edges,
+                total_weight,
+            }
+        }
+    }
+
+    /// Kruskal's algorithm finds a minimum spanning forest by adding edges in increasing order of weight,
+    /// skipping every edge whose endpoints are already connected.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`.
+    ///
+    /// # Returns
+    ///
+    /// * `SpanningTree` - The tree edges and their total weight. A disconnected graph gives one tree per component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// g.add_edge(0, 1, 1);
+    /// g.add_edge(1, 2, 2);
+    /// g.add_edge(0, 2, 3);
+    /// g.add_edge(2, 3, 4);
+    ///
+    /// let tree = kruskal(&g);
+    /// assert_eq!(tree.edges, vec![(0, 1, 1), (1, 2, 2), (2, 3, 4)]);
+    /// assert_eq!(tree.total_weight, 7);
+    /// ```
+    pub fn kruskal(g: &Graph) -> SpanningTree {
+        let mut edges = g.edge_list();
+        edges.sort_by_key(|&(u, v, w)| (w, u, v)); //Sorting the edges by weight
+        let mut sets = UnionFind::new(g.vertex_count());
+        let mut tree = Vec::new();
+        for (u, v, w) in edges {
+            //An edge joining two different trees of the forest is the cheapest way to connect them
+            if sets.union(u, v) {
+                tree.push((u, v, w));
+            }
+        }
+        SpanningTree::from_edges(tree)
+    }
+
+    /// Prim's algorithm grows a minimum spanning tree from a vertex by repeatedly adding the cheapest edge leaving the tree.
+    /// It is restarted from every vertex not yet reached, so a disconnected graph gives a minimum spanning forest.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`.
+    ///
+    /// # Returns
+    ///
+    /// * `SpanningTree` - The tree edges, given as `(tree vertex, new vertex, weight)`, and their total weight.
+    pub fn prim(g: &Graph) -> SpanningTree {
+        let n = g.vertex_count();
+        let mut in_tree = vec![false; n];
+        let mut tree = Vec::new();
+        let mut heap = BinaryHeap::new(); //Min-heap of (weight, from, to) for edges leaving the tree
+
+        for root in 0..n {
+            if in_tree[root] {
+                continue;
+            }
+            in_tree[root] = true;
+            for (v, w) in g.neighbors(root) {
+                heap.push(Reverse((w, root, v)));
+            }
+            while let Some(Reverse((w, u, v))) = heap.pop() {
+                if in_tree[v] {
+                    continue; //Both endpoints are already in the tree
+                }
+                in_tree[v] = true;
+                tree.push((u, v, w));
+                for (x, wx) in g.neighbors(v) {
+                    if !in_tree[x] {
+                        heap.push(Reverse((wx, v, x)));
+                    }
+                }
+            }
+        }
+        SpanningTree::from_edges(tree)
+    }
+
+    /// Borůvka's algorithm finds a minimum spanning forest in rounds.
+    /// In every round each component picks its cheapest outgoing edge and all of these edges are added at once,
+    /// which at least halves the number of components per round.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A weighted undirected graph built with `dijkstras::Graph::add_edge`.
+    ///
+    /// # Returns
+    ///
+    /// * `SpanningTree` - The tree edges and their total weight.
+    pub fn boruvka(g: &Graph) -> SpanningTree {
+        let n = g.vertex_count();
+        let edges = g.edge_list();
+        let mut sets = UnionFind::new(n);
+        let mut tree = Vec::new();
+
+        loop {
+            //cheapest[c] is the index of the cheapest edge leaving the component with representative c
+            let mut cheapest: Vec<Option<usize>> = vec![None; n];
+            for (i, &(u, v, w)) in edges.iter().enumerate() {
+                let (cu, cv) = (sets.find(u), sets.find(v));
+                if cu == cv {
+                    continue;
+                }
+                for c in [cu, cv] {
+                    //Ties are broken by edge index so that the chosen edges never form a cycle
+                    let better = match cheapest[c] {
+                        None => true,
+                        Some(j) => (w, i) < (edges[j].2, j),
+                    };
+                    if better {
+                        cheapest[c] = Some(i);
+                    }
+                }
+            }
+
+            let mut added = false;
+            for i in cheapest.into_iter().flatten() {
+                let (u, v, w) = edges[i];
+                //Two components may have picked the same edge, so add it only once
+                if sets.union(u, v) {
+                    tree.push((u, v, w));
+                    added = true;
+                }
+            }
+            if !added {
+                break; //No component has an outgoing edge any more
+            }
+        }
+        SpanningTree::from_edges(tree)
+    }
+
+    /// Finds a minimum spanning tree of a weighted undirected graph entered by the user with Kruskal's, Prim's and Borůvka's algorithms.
+    ///
+    /// # Input
+    ///
+    /// * `vertices` - Total number of vertices in the graph.
+    ///
+    /// * `edges` - Total Number of edges in the graph.
+    ///
+    /// * `Source(s) Destination(d) Weight(w) ` - Source, Destination and Weight for each edge in the graph.
+    ///
+    /// # Output
+    ///
+    /// Prints the tree edges and total weight found by every algorithm.
+    ///
+    /// # Sample Input
+    /// ```
+    /// Please Enter Number of Vertices in the Graph : 4
+    /// Please Enter Number of edges in the graph : 4
+    /// Please Enter Edge 1 values
+    /// Source : 0
+    /// Destination : 1
+    /// Weight(>0) : 1
+    /// Please Enter Edge 2 values
+    /// Source : 1
+    /// Destination : 2
+    /// Weight(>0) : 2
+    /// Please Enter Edge 3 values
+    /// Source : 0
+    /// Destination : 2
+    /// Weight(>0) : 3
+    /// Please Enter Edge 4 values
+    /// Source : 2
+    /// Destination : 3
+    /// Weight(>0) : 4
+    /// ```
+    /// # Sample Output
+    /// ```
+    /// Kruskal's algorithm: edges [(0, 1, 1), (1, 2, 2), (2, 3, 4)], total weight 7
+    /// Prim's algorithm: edges [(0, 1, 1), (1, 2, 2), (2, 3, 4)], total weight 7
+    /// Borůvka's algorithm: edges [(0, 1, 1), (1, 2, 2), (2, 3, 4)], total weight 7
+    /// ```
+    pub fn mst() {
+        // Create an empty string to store the number of vertices
+        let mut ve = String::new();
+
+        // Printing the introduction message
+        println!("******Minimum Spanning Tree*******");
+        println!("******************");
+
+        // Prompting user to input number of vertices in the Graph
+        print!("Please Enter Number of Vertices in the Graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut ve)
+            .expect("Please Enter Valid number for vertices.");
+        let vertices: usize = ve
+            .trim()
+            .parse()
+            .expect("Invalid input for number of vertices");
+
+        // Prompting user to input the number of edges
+        let mut edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut edges)
+            .expect("Please Enter Valid Input for number of Edges.");
+        let edges: i32 = edges
+            .trim()
+            .parse()
+            .expect("Invalid input for number of edges");
+
+        // Reading all edges source, destination and weight from the user
+        let g = add_edges(vertices, edges);
+
+        println!("******************");
+        for (name, tree) in [
+            ("Kruskal's algorithm", kruskal(&g)),
+            ("Prim's algorithm", prim(&g)),
+            ("Borůvka's algorithm", boruvka(&g)),
+        ] {
+            println!(
+                "{}: edges {:?}, total weight {}",
+                name, tree.edges, tree.total_weight
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mst::*;
+    use crate::list_of_algorithms::dijkstras::dijkstras::Graph;
+
+    fn sorted(mut edges: Vec<(usize, usize, i32)>) -> Vec<(usize, usize, i32)> {
+        for e in edges.iter_mut() {
+            *e = (e.0.min(e.1), e.0.max(e.1), e.2);
+        }
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let mut g = Graph::new(5);
+        g.add_edge(0, 1, 10);
+        g.add_edge(0, 2, 5);
+        g.add_edge(1, 3, 1);
+        g.add_edge(2, 1, 3);
+        g.add_edge(2, 3, 8);
+        g.add_edge(2, 4, 2);
+        g.add_edge(3, 4, 4);
+
+        let expected = vec![(0, 2, 5), (1, 2, 3), (1, 3, 1), (2, 4, 2)];
+        for tree in [kruskal(&g), prim(&g), boruvka(&g)] {
+            assert_eq!(tree.total_weight, 11);
+            assert_eq!(sorted(tree.edges), expected);
+        }
+    }
+
+    #[test]
+    fn test_spanning_forest() {
+        //Two components and an isolated vertex give a forest with 3 edges
+        let mut g = Graph::new(6);
+        g.add_edge(0, 1, 4);
+        g.add_edge(1, 2, 1);
+        g.add_edge(0, 2, 2);
+        g.add_edge(3, 4, 7);
+        g.add_edge(3, 3, 1);
+
+        for tree in [kruskal(&g), prim(&g), boruvka(&g)] {
+            assert_eq!(tree.total_weight, 10);
+            assert_eq!(sorted(tree.edges), vec![(0, 2, 2), (1, 2, 1), (3, 4, 7)]);
+        }
+    }
+
+    #[test]
+    fn test_equal_weights_and_parallel_edges() {
+        let mut g = Graph::new(4);
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 0, 1);
+        g.add_edge(0, 1, -2);
+
+        for tree in [kruskal(&g), prim(&g), boruvka(&g)] {
+            assert_eq!(tree.edges.len(), 3);
+            assert_eq!(tree.total_weight, 0);
+        }
+    }
+}
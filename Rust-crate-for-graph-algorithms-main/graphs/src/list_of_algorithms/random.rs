@@ -0,0 +1,29 @@
+pub mod random {
+    /// A small xorshift pseudo-random number generator, so that randomised results are reproducible from their seed.
+    pub(crate) struct XorShift {
+        state: u64,
+    }
+
+    impl XorShift {
+        /// Creates a generator from a seed. Every seed, including 0, gives a usable sequence.
+        pub(crate) fn new(seed: u64) -> Self {
+            let state = seed ^ 0x9E37_79B9_7F4A_7C15;
+            XorShift {
+                state: if state == 0 { 1 } else { state },
+            }
+        }
+
+        //Returns the next pseudo-random number
+        fn next_u64(&mut self) -> u64 {
+            self.state ^= self.state << 13;
+            self.state ^= self.state >> 7;
+            self.state ^= self.state << 17;
+            self.state
+        }
+
+        /// Returns a pseudo-random number in `0..n`.
+        pub(crate) fn below(&mut self, n: usize) -> usize {
+            (self.next_u64() % n as u64) as usize
+        }
+    }
+}
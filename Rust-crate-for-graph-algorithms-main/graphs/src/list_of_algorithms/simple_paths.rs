@@ -0,0 +1,250 @@
+pub mod simple_paths {
+    //Importng necessary libraries
+    use crate::list_of_algorithms::dfs::dfs::{add_edges, Graph};
+    use std::io::{stdin, stdout, Write};
+
+    /// A lazy iterator over the simple paths between two vertices, created by `all_simple_paths`.
+    /// It walks the graph like `dfs::Graph::d_fs`, but keeps the recursion on an explicit stack so that
+    /// the search can pause after every path, and unmarks a vertex when it is left so that other paths may reuse it.
+    pub struct SimplePaths<'a> {
+        g: &'a Graph,
+        target: usize,
+        stack: Vec<(usize, usize)>, //vertices of the current path with the index of their next neighbor to try
+        on_path: Vec<bool>,
+        max_depth: usize,
+        remaining: usize,
+    }
+
+    impl Iterator for SimplePaths<'_> {
+        type Item = Vec<usize>;
+
+        fn next(&mut self) -> Option<Vec<usize>> {
+            if self.remaining == 0 {
+                return None;
+            }
+            if self.stack.len() == 1 && self.stack[0].0 == self.target {
+                //The path from a vertex to itself is that vertex alone
+                self.stack.clear();
+                self.remaining -= 1;
+                return Some(vec![self.target]);
+            }
+
+            while let Some(&(u, i)) = self.stack.last() {
+                if i == self.g.edges[u].len() {
+                    //Every neighbor of u has been tried, so step back like the recursion would return
+                    self.stack.pop();
+                    self.on_path[u] = false;
+                    continue;
+                }
+                self.stack.last_mut()?.1 += 1;
+                let v = self.g.edges[u][i];
+                if self.on_path[v] {
+                    continue;
+                }
+                if v == self.target {
+                    if self.stack.len() > self.max_depth {
+                        continue; //The path through u is one edge too long
+                    }
+                    self.remaining -= 1;
+                    let mut path: Vec<usize> = self.stack.iter().map(|&(x, _)| x).collect();
+                    path.push(v);
+                    return Some(path);
+                }
+                //Going deeper only helps while the target can still be reached within the depth limit
+                if self.stack.len() < self.max_depth {
+                    self.on_path[v] = true;
+                    self.stack.push((v, 0));
+                }
+            }
+            None
+        }
+    }
+
+    /// Enumerates all simple paths from `s` to `t` with Depth-First Search, one path at a time.
+    /// Only the current path is kept in memory, so arbitrarily many paths can be consumed without collecting them.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A directed graph built with `dfs::Graph::add_edge`. Parallel edges give the same path once per edge.
+    /// * `s` - The first vertex of every path.
+    /// * `t` - The last vertex of every path.
+    /// * `max_depth` - When given, only paths with at most this many edges are produced.
+    /// * `max_paths` - When given, the iterator stops after this many paths.
+    ///
+    /// # Returns
+    ///
+    /// * `SimplePaths` - An iterator yielding the vertices of every path in Depth-First Search order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut g = Graph::new(4);
+    /// g.add_edge(0, 1);
+    /// g.add_edge(0, 2);
+    /// g.add_edge(1, 2);
+    /// g.add_edge(1, 3);
+    /// g.add_edge(2, 3);
+    ///
+    /// let paths: Vec<Vec<usize>> = all_simple_paths(&g, 0, 3, None, None).collect();
+    /// assert_eq!(paths, vec![vec![0, 1, 2, 3], vec![0, 1, 3], vec![0, 2, 3]]);
+    ///
+    /// //Limits are applied while searching
+    /// assert_eq!(all_simple_paths(&g, 0, 3, Some(2), None).count(), 2);
+    /// assert_eq!(all_simple_paths(&g, 0, 3, None, Some(1)).count(), 1);
+    /// ```
+    pub fn all_simple_paths(
+        g: &Graph,
+        s: usize,
+        t: usize,
+        max_depth: Option<usize>,
+        max_paths: Option<usize>,
+    ) -> SimplePaths<'_> {
+        let mut on_path = vec![false; g.edges.len()];
+        on_path[s] = true;
+        SimplePaths {
+            g,
+            target: t,
+            stack: vec![(s, 0)],
+            on_path,
+            max_depth: max_depth.unwrap_or(usize::MAX),
+            remaining: max_paths.unwrap_or(usize::MAX),
+        }
+    }
+
+    /// Lists the simple paths between two vertices of a graph entered by the user.
+    ///
+    /// # Input
+    /// * `no_of_vertices` - Input the number of vertices in the graph
+    /// * `no_of_edges` - Input the number of edges in the graph
+    /// * `source` - The source vertex of an edge in the graph
+    /// * `destination` - The destination of an edge in the graph
+    /// * `start` - The first vertex of the paths
+    /// * `end` - The last vertex of the paths
+    /// * `max_depth` - The largest number of edges on a path, 0 for no limit
+    /// * `max_paths` - The largest number of paths to list, 0 for no limit
+    ///
+    /// # Output
+    ///
+    /// Prints every path as soon as it is found
+    ///
+    /// # Sample input
+    /// ```
+    /// Please Enter Number of Vertices : 4
+    ///Please Enter Number of edges in the graph : 4
+    ///Source : 0
+    ///Destination : 1
+    ///Source : 1
+    ///Destination : 3
+    ///Source : 0
+    ///Destination : 2
+    ///Source : 2
+    ///Destination : 3
+    ///Enter Start Vertex : 0
+    ///Enter End Vertex : 3
+    ///Enter Maximum Number of Edges on a Path (0 for no limit) : 0
+    ///Enter Maximum Number of Paths (0 for no limit) : 0
+    /// ```
+    ///  # Sample output
+    /// ```
+    ///Path 1 : [0, 1, 3]
+    ///Path 2 : [0, 2, 3]
+    /// ```
+    pub fn simple_paths() {
+        //read the number of vertices from the console
+        let mut vertex = String::new();
+        println!("******All Simple Paths*********");
+        println!("****************************************************");
+        print!("Please Enter Number of Vertices : ");
+        let _ = stdout().flush();
+        stdin()
+            .read_line(&mut vertex)
+            .expect("Enter valid number of vertices");
+        let vertices: usize = vertex.trim().parse().expect("Invalid input");
+        //get number of edges in the graph
+        let mut n_edges = String::new();
+        print!("Please Enter Number of edges in the graph : ");
+        let _ = stdout().flush();
+        stdin().read_line(&mut n_edges).expect("Enter Valid Input");
+        let n_edges: i32 = n_edges.trim().parse().expect("Invalid input for edges");
+        //assign the vertices to each edge from the console
+        let g = add_edges(vertices, n_edges);
+
+        //get the endpoints and the limits, where 0 means no limit
+        let mut values = Vec::new();
+        for prompt in [
+            "Enter Start Vertex : ",
+            "Enter End Vertex : ",
+            "Enter Maximum Number of Edges on a Path (0 for no limit) : ",
+            "Enter Maximum Number of Paths (0 for no limit) : ",
+        ] {
+            let mut value = String::new();
+            print!("{}", prompt);
+            let _ = stdout().flush();
+            stdin().read_line(&mut value).expect("Enter Valid Input");
+            let value: usize = value.trim().parse().expect("Invalid input");
+            values.push(value);
+        }
+        let limit = |x: usize| if x == 0 { None } else { Some(x) };
+
+        println!("******************");
+        let paths = all_simple_paths(&g, values[0], values[1], limit(values[2]), limit(values[3]));
+        for (i, path) in paths.enumerate() {
+            println!("Path {} : {:?}", i + 1, path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple_paths::*;
+    use crate::list_of_algorithms::dfs::dfs::Graph;
+
+    #[test]
+    fn test_all_paths_in_dag() {
+        //A ladder of three diamonds has 2 * 2 * 2 paths from the top to the bottom
+        let mut g = Graph::new(7);
+        for k in [0, 2, 4] {
+            g.add_edge(k, k + 1);
+            g.add_edge(k, k + 2);
+            g.add_edge(k + 1, k + 2);
+        }
+        let paths: Vec<Vec<usize>> = all_simple_paths(&g, 0, 6, None, None).collect();
+        assert_eq!(paths.len(), 8);
+        assert_eq!(paths[0], vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(paths[7], vec![0, 2, 4, 6]);
+        assert_eq!(all_simple_paths(&g, 0, 6, Some(4), None).count(), 4);
+        assert_eq!(all_simple_paths(&g, 6, 0, None, None).count(), 0);
+    }
+
+    #[test]
+    fn test_cycles_are_not_repeated() {
+        //Every edge in both directions, as dfs::add_edges builds it
+        let mut g = Graph::new(4);
+        for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+            g.add_edge(u, v);
+            g.add_edge(v, u);
+        }
+        let paths: Vec<Vec<usize>> = all_simple_paths(&g, 0, 3, None, None).collect();
+        assert_eq!(paths, vec![vec![0, 1, 2, 3], vec![0, 2, 3]]);
+        assert_eq!(all_simple_paths(&g, 1, 1, None, None).collect::<Vec<_>>(), vec![vec![1]]);
+    }
+
+    #[test]
+    fn test_lazy_with_limits() {
+        //A complete graph on 12 vertices has far too many paths to collect, but the first few come at once
+        let n = 12;
+        let mut g = Graph::new(n);
+        for u in 0..n {
+            for v in 0..n {
+                if u != v {
+                    g.add_edge(u, v);
+                }
+            }
+        }
+        let mut paths = all_simple_paths(&g, 0, n - 1, None, Some(3));
+        assert_eq!(paths.next().map(|p| p.len()), Some(n));
+        assert_eq!(paths.count(), 2);
+        assert_eq!(all_simple_paths(&g, 0, n - 1, Some(1), None).count(), 1);
+        assert_eq!(all_simple_paths(&g, 0, n - 1, Some(2), None).count(), n - 1);
+    }
+}
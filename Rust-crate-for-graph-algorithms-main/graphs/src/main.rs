@@ -5,7 +5,33 @@ pub use crate::list_of_algorithms::{ // Using the algorithms from 'list_of_algor
     bellmanford::bellmanford::bellmanford,
     dfs::dfs::dfs,
     kosaraju::kosaraju::kosaraju,
-    bfs::bfs::bfs
+    bfs::bfs::bfs,
+    biconnected::biconnected::biconnected,
+    connected_components::connected_components::connected,
+    connected_components::connected_components::weakly_connected,
+    mst::mst::mst,
+    flow::flow::max_flow,
+    min_cost_flow::min_cost_flow::min_cost,
+    bipartite_matching::bipartite_matching::matching,
+    hungarian::hungarian::assignment,
+    blossom::blossom::general_matching,
+    bipartite::bipartite::bipartite,
+    cycles::cycles::cycles,
+    euler::euler::euler,
+    euler::euler::postman,
+    simple_paths::simple_paths::simple_paths,
+    k_shortest_paths::k_shortest_paths::k_shortest,
+    distance_metrics::distance_metrics::distances,
+    centrality::centrality::centrality,
+    link_analysis::link_analysis::link_analysis,
+    community::community::communities,
+    clustering::clustering::clustering,
+    cliques::cliques::cliques,
+    coloring::coloring::coloring,
+    isomorphism::isomorphism::isomorphism,
+    dominators::dominators::dominators,
+    closure::closure::closure,
+    min_cut::min_cut::min_cut
 };
 
 /// The `main` function displays a list of different algorithms and allows the user to select one.
@@ -18,7 +44,33 @@ pub use crate::list_of_algorithms::{ // Using the algorithms from 'list_of_algor
 /// 3. Depth-First Search algorithm
 /// 4. Kosaraju’s algorithm
 /// 5. Breadth-First Search algorithm
-/// 6. Exit
+/// 6. Biconnected components
+/// 7. Connected components
+/// 8. Weakly connected components
+/// 9. Minimum spanning tree
+/// 10. Maximum flow
+/// 11. Min-cost flow
+/// 12. Bipartite matching
+/// 13. Hungarian algorithm
+/// 14. General matching
+/// 15. Bipartiteness check
+/// 16. Cycle detection
+/// 17. Eulerian path
+/// 18. Chinese postman
+/// 19. All simple paths
+/// 20. K shortest paths
+/// 21. Distance metrics
+/// 22. Centrality
+/// 23. Link analysis
+/// 24. Community detection
+/// 25. Triangles and cores
+/// 26. Cliques
+/// 27. Graph colouring
+/// 28. Graph isomorphism
+/// 29. Dominators
+/// 30. Transitive closure
+/// 31. Minimum cut
+/// 32. Exit
 /// ******************
 /// Please Enter your choice (ex:1): 1
 /// ******************
@@ -36,12 +88,38 @@ fn main() {
         println!("3. Depth-First Search algorithm");
         println!("4. Kosaraju's algorithm");
         println!("5. Breadth-First Search algorithm");
-        println!("6. Exit");
+        println!("6. Biconnected components");
+        println!("7. Connected components");
+        println!("8. Weakly connected components");
+        println!("9. Minimum spanning tree");
+        println!("10. Maximum flow");
+        println!("11. Min-cost flow");
+        println!("12. Bipartite matching");
+        println!("13. Hungarian algorithm");
+        println!("14. General matching");
+        println!("15. Bipartiteness check");
+        println!("16. Cycle detection");
+        println!("17. Eulerian path");
+        println!("18. Chinese postman");
+        println!("19. All simple paths");
+        println!("20. K shortest paths");
+        println!("21. Distance metrics");
+        println!("22. Centrality");
+        println!("23. Link analysis");
+        println!("24. Community detection");
+        println!("25. Triangles and cores");
+        println!("26. Cliques");
+        println!("27. Graph colouring");
+        println!("28. Graph isomorphism");
+        println!("29. Dominators");
+        println!("30. Transitive closure");
+        println!("31. Minimum cut");
+        println!("32. Exit");
         println!("******************");
         print!("Please Enter your choice (ex:1): "); // Prompting the user for input
         let _= stdout().flush(); // Flushing the output buffer to ensure prompt is displayed before taking input from user
-        stdin().read_line(&mut choice).expect("Please Enter make a valid Selection for Algorithm(1-6)."); // Reading users input and handling errors
-        let number: i32 = choice.trim().parse().expect("Invalid Selection for Algorithm(Select from 1-6)"); // Parsing users input as an integer and handling errors
+        stdin().read_line(&mut choice).expect("Please Enter make a valid Selection for Algorithm(1-32)."); // Reading users input and handling errors
+        let number: i32 = choice.trim().parse().expect("Invalid Selection for Algorithm(Select from 1-32)"); // Parsing users input as an integer and handling errors
         println!("******************");
         match number { // Matching user's input to call the appropriate algorithm function
             1 => dijkstras(),
@@ -49,7 +127,33 @@ fn main() {
             3 => dfs(),
             4 => kosaraju(),
             5 => bfs(),
-            6 => break, // Exiting the loop if user chooses to exit
+            6 => biconnected(),
+            7 => connected(),
+            8 => weakly_connected(),
+            9 => mst(),
+            10 => max_flow(),
+            11 => min_cost(),
+            12 => matching(),
+            13 => assignment(),
+            14 => general_matching(),
+            15 => bipartite(),
+            16 => cycles(),
+            17 => euler(),
+            18 => postman(),
+            19 => simple_paths(),
+            20 => k_shortest(),
+            21 => distances(),
+            22 => centrality(),
+            23 => link_analysis(),
+            24 => communities(),
+            25 => clustering(),
+            26 => cliques(),
+            27 => coloring(),
+            28 => isomorphism(),
+            29 => dominators(),
+            30 => closure(),
+            31 => min_cut(),
+            32 => break, // Exiting the loop if user chooses to exit
             _ => println!("Invalid Selection for Algorithm"), // Handling invalid user input
         }
     }